@@ -8,7 +8,6 @@ use grapple_db::{
             macros::charybdis_model,
             types::{Text, Uuid},
         },
-        stream::PagableCharybdisStream,
         Client, ConnectionParams, CrudParams,
     },
     Pagable,
@@ -88,13 +87,11 @@ async fn main() -> Result<()> {
     // -- STREAM USERS
     let mut users_count = 0;
 
-    let stream = client
-        .stream(User::find_by_name("find me".to_string()))
-        // .stream(User::find_all())
+    let mut pagable_stream = client
+        .paged_stream(User::find_by_name("find me".to_string()), 5)
+        // .paged_stream(User::find_all(), 5)
         .await?;
 
-    let mut pagable_stream = PagableCharybdisStream::new(stream, 5);
-
     while let Some(users) = pagable_stream.next_page().await {
         for user in users {
             println!("{} {:?} {:?}", user.id, user.name, user.pwd);
@@ -5,6 +5,9 @@
 // region:    --- Modules
 
 use async_trait::async_trait;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
 
 // -- Modules
 #[cfg(feature = "redis")]
@@ -12,6 +15,9 @@ pub mod redis;
 #[cfg(feature = "scylla")]
 pub mod scylla;
 
+#[cfg(all(test, any(feature = "redis", feature = "scylla")))]
+mod test_utils;
+
 // endregion: --- Modules
 
 /// Trait for paginating through stream models.
@@ -26,6 +32,10 @@ pub mod scylla;
 /// - `skip_page`: Skips the current page in the stream without retrieving items.
 /// - `skip_pages`: Skips `page_count` pages in the stream without retrieving items.
 /// - `page_items`: Returns the items of the current page.
+/// - `next_page_owned`: Fetches the next page of items as an owned vector.
+/// - `take`: Limits the total number of items returned across all pages.
+/// - `and_then_page`: Transforms each page with a batched, asynchronous function.
+/// - `filter_page`: Drops the items of each page not matching a predicate.
 #[async_trait]
 pub trait Pagable<E>
 where
@@ -64,4 +74,572 @@ where
     ///
     /// A slice of the items currently stored in the page.
     fn page_items(&self) -> &[E];
+
+    /// Fetches the next page of items as an owned vector.
+    ///
+    /// This method works like `next_page`, but clones the items of the page into
+    /// a `Vec`, so that pages can be buffered or sent across tasks.
+    ///
+    /// # Returns
+    ///
+    /// An optional vector of items for the next page.
+    async fn next_page_owned(&mut self) -> Option<Vec<E>>
+    where
+        E: Clone,
+    {
+        self.next_page().await.map(|items| items.to_vec())
+    }
+
+    /// Limits the total number of items returned across all pages.
+    ///
+    /// The returned `TakePagable` stops yielding pages once `max` items have been
+    /// returned, trimming the final page if it would exceed the limit.
+    ///
+    /// # Parameters
+    ///
+    /// - `max`: The maximum number of items to return.
+    ///
+    /// # Returns
+    ///
+    /// A `TakePagable` wrapping this pagable.
+    fn take(self, max: usize) -> TakePagable<Self>
+    where
+        Self: Sized,
+    {
+        TakePagable::new(self, max)
+    }
+
+    /// Transforms each page with an asynchronous function called once per page.
+    ///
+    /// This is useful for enriching items with a secondary lookup, e.g. batch-fetching
+    /// the authors of a page of posts, instead of looking up every item separately.
+    /// The function receives the whole page and returns the items of the new page.
+    /// Skipped pages are not passed to the function.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function transforming a page of items.
+    ///
+    /// # Returns
+    ///
+    /// An `AndThenPage` wrapping this pagable.
+    fn and_then_page<T, F>(self, f: F) -> AndThenPage<Self, F, E, T>
+    where
+        Self: Sized,
+        F: for<'p> FnMut(&'p [E]) -> Pin<Box<dyn Future<Output = Vec<T>> + Send + 'p>>,
+    {
+        AndThenPage::new(self, f)
+    }
+
+    /// Drops the items of each page that don't match a predicate.
+    ///
+    /// This is useful for hiding rows that can't be filtered by the query, e.g.
+    /// soft-deleted rows. By default page boundaries are preserved: every fetched
+    /// page is filtered on its own, so a page may hold fewer than `per_page` items
+    /// or even none, and the pagination ends only when the wrapped pagable ends.
+    /// Use `FilterPage::with_refill` to fetch more items and refill the pages instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `pred`: The predicate items must match to be kept.
+    ///
+    /// # Returns
+    ///
+    /// A `FilterPage` wrapping this pagable.
+    fn filter_page<F>(self, pred: F) -> FilterPage<Self, F, E>
+    where
+        Self: Sized,
+        F: FnMut(&E) -> bool,
+    {
+        FilterPage::new(self, pred)
+    }
+}
+
+/// A pagable over an owned vector of items.
+///
+/// This struct pages through items held in memory with the same semantics as
+/// `PagableCharybdisStream`: the final page may be partial, `next_page` returns
+/// `None` once the items are exhausted, and a page size of 0 yields no pages
+/// without consuming the items. It allows unit-testing code consuming pages
+/// without a database.
+///
+/// # Fields
+///
+/// - `items`: The items that were not paged through yet.
+/// - `per_page`: The number of items to retrieve per page.
+/// - `page_items`: A vector that holds the items of the current page.
+/// - `exhausted`: Whether the items are known to be drained.
+///
+/// # Examples
+///
+/// ```rust
+/// use grapple_db::{InMemoryPagable, Pagable};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut pages = InMemoryPagable::new(vec![1, 2, 3, 4, 5], 2);
+///
+/// assert_eq!(Some(&[1, 2][..]), pages.next_page().await);
+/// assert_eq!(Some(&[3, 4][..]), pages.next_page().await);
+/// assert_eq!(Some(&[5][..]), pages.next_page().await);
+/// assert_eq!(None, pages.next_page().await);
+/// # }
+/// ```
+pub struct InMemoryPagable<E> {
+    items: std::vec::IntoIter<E>,
+    per_page: usize,
+    page_items: Vec<E>,
+    exhausted: bool,
+}
+
+impl<E> InMemoryPagable<E> {
+    /// Creates a new instance of `InMemoryPagable`.
+    ///
+    /// # Parameters
+    ///
+    /// - `items`: The items to paginate.
+    /// - `per_page`: The number of items to retrieve per page.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InMemoryPagable`.
+    pub fn new(items: Vec<E>, per_page: usize) -> Self {
+        Self {
+            items: items.into_iter(),
+            per_page,
+            page_items: Vec::with_capacity(per_page),
+            exhausted: false,
+        }
+    }
+
+    /// Checks whether the items are drained.
+    ///
+    /// Like `PagableCharybdisStream::is_exhausted`, the flag becomes `true` once a page
+    /// returned fewer than `per_page` items.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no more pages are available, `false` otherwise.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Changes the number of items retrieved per page.
+    ///
+    /// The new size affects subsequent `next_page` and `skip_page` calls. The items of the
+    /// current page are kept.
+    ///
+    /// # Parameters
+    ///
+    /// - `per_page`: The new number of items to retrieve per page.
+    pub fn set_per_page(&mut self, per_page: usize) {
+        self.per_page = per_page;
+    }
+}
+
+#[async_trait]
+impl<E> Pagable<E> for InMemoryPagable<E>
+where
+    E: Send + Sync,
+{
+    async fn next_page(&mut self) -> Option<&[E]> {
+        self.page_items.clear();
+        self.page_items
+            .extend(self.items.by_ref().take(self.per_page));
+
+        if self.page_items.len() < self.per_page {
+            self.exhausted = true;
+        }
+
+        if self.page_items.is_empty() {
+            None
+        } else {
+            Some(self.page_items())
+        }
+    }
+
+    async fn skip_page(&mut self) {
+        self.page_items.clear();
+
+        if self.items.by_ref().take(self.per_page).count() < self.per_page {
+            self.exhausted = true;
+        }
+    }
+
+    #[inline]
+    fn page_items(&self) -> &[E] {
+        &self.page_items
+    }
+}
+
+/// A pagable wrapper limiting the total number of items across pages.
+///
+/// This struct is created by `Pagable::take`. Skipped pages count towards
+/// the limit as well.
+///
+/// # Fields
+///
+/// - `inner`: The wrapped pagable.
+/// - `remaining`: The number of items that may still be returned.
+/// - `page_len`: The number of items of the current (possibly trimmed) page.
+pub struct TakePagable<P> {
+    inner: P,
+    remaining: usize,
+    page_len: usize,
+}
+
+impl<P> TakePagable<P> {
+    /// Creates a new instance of `TakePagable`.
+    ///
+    /// # Parameters
+    ///
+    /// - `inner`: The pagable to wrap.
+    /// - `max`: The maximum number of items to return.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `TakePagable`.
+    pub fn new(inner: P, max: usize) -> Self {
+        Self {
+            inner,
+            remaining: max,
+            page_len: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl<E, P> Pagable<E> for TakePagable<P>
+where
+    E: Send + Sync,
+    P: Pagable<E> + Send,
+{
+    async fn next_page(&mut self) -> Option<&[E]> {
+        self.page_len = 0;
+
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let len = self.inner.next_page().await?.len().min(self.remaining);
+        self.remaining -= len;
+        self.page_len = len;
+
+        Some(self.page_items())
+    }
+
+    async fn skip_page(&mut self) {
+        self.page_len = 0;
+
+        if self.remaining == 0 {
+            return;
+        }
+
+        // Skipped items count towards the limit, so the page has to be fetched
+        let len = self.inner.next_page().await.map_or(0, |items| items.len());
+        self.remaining -= len.min(self.remaining);
+    }
+
+    #[inline]
+    fn page_items(&self) -> &[E] {
+        &self.inner.page_items()[..self.page_len]
+    }
+}
+
+/// A pagable wrapper transforming each page with an asynchronous function.
+///
+/// This struct is created by `Pagable::and_then_page`.
+///
+/// # Fields
+///
+/// - `inner`: The wrapped pagable.
+/// - `f`: The function transforming a page of items.
+/// - `page_items`: The transformed items of the current page.
+pub struct AndThenPage<P, F, E, T> {
+    inner: P,
+    f: F,
+    page_items: Vec<T>,
+    _marker: PhantomData<fn(&E)>,
+}
+
+impl<P, F, E, T> AndThenPage<P, F, E, T> {
+    /// Creates a new instance of `AndThenPage`.
+    ///
+    /// # Parameters
+    ///
+    /// - `inner`: The pagable to wrap.
+    /// - `f`: The function transforming a page of items.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `AndThenPage`.
+    pub fn new(inner: P, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            page_items: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, F, E, T> Pagable<T> for AndThenPage<P, F, E, T>
+where
+    E: Send + Sync,
+    T: Send + Sync,
+    P: Pagable<E> + Send,
+    F: for<'p> FnMut(&'p [E]) -> Pin<Box<dyn Future<Output = Vec<T>> + Send + 'p>> + Send,
+{
+    async fn next_page(&mut self) -> Option<&[T]> {
+        self.page_items.clear();
+
+        let items = self.inner.next_page().await?;
+        self.page_items = (self.f)(items).await;
+
+        Some(self.page_items())
+    }
+
+    async fn skip_page(&mut self) {
+        self.page_items.clear();
+        self.inner.skip_page().await;
+    }
+
+    #[inline]
+    fn page_items(&self) -> &[T] {
+        &self.page_items
+    }
+}
+
+/// A pagable wrapper dropping the items not matching a predicate.
+///
+/// This struct is created by `Pagable::filter_page`. Without a refill size,
+/// every page of the wrapped pagable is filtered on its own and may come out
+/// partial or empty. With a refill size set by `with_refill`, pages of the
+/// wrapped pagable are fetched until a page of that size is filled; the items
+/// left over are kept for the next page, and only the final page may be
+/// partial. Skipped pages are filtered as well, so with a refill size a skipped
+/// page skips `refill` matching items.
+///
+/// # Fields
+///
+/// - `inner`: The wrapped pagable.
+/// - `pred`: The predicate items must match to be kept.
+/// - `refill`: The size of refilled pages, if refilling is enabled.
+/// - `buffer`: Matching items fetched but not returned yet (refilling only).
+/// - `inner_exhausted`: Whether the wrapped pagable returned its last page (refilling only).
+/// - `page_items`: The filtered items of the current page.
+pub struct FilterPage<P, F, E> {
+    inner: P,
+    pred: F,
+    refill: Option<usize>,
+    buffer: Vec<E>,
+    inner_exhausted: bool,
+    page_items: Vec<E>,
+}
+
+impl<P, F, E> FilterPage<P, F, E> {
+    /// Creates a new instance of `FilterPage` preserving page boundaries.
+    ///
+    /// # Parameters
+    ///
+    /// - `inner`: The pagable to wrap.
+    /// - `pred`: The predicate items must match to be kept.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `FilterPage`.
+    pub fn new(inner: P, pred: F) -> Self {
+        Self {
+            inner,
+            pred,
+            refill: None,
+            buffer: Vec::new(),
+            inner_exhausted: false,
+            page_items: Vec::new(),
+        }
+    }
+
+    /// Refills filtered pages up to `per_page` items.
+    ///
+    /// Pages of the wrapped pagable are fetched until `per_page` matching items
+    /// are collected or the wrapped pagable ends. A size of 0 is treated as 1.
+    ///
+    /// # Parameters
+    ///
+    /// - `per_page`: The number of items of a refilled page.
+    ///
+    /// # Returns
+    ///
+    /// The `FilterPage` with refilling enabled (builder pattern).
+    pub fn with_refill(mut self, per_page: usize) -> Self {
+        self.refill = Some(per_page.max(1));
+        self
+    }
+}
+
+#[async_trait]
+impl<P, F, E> Pagable<E> for FilterPage<P, F, E>
+where
+    E: Clone + Send + Sync,
+    P: Pagable<E> + Send,
+    F: FnMut(&E) -> bool + Send,
+{
+    async fn next_page(&mut self) -> Option<&[E]> {
+        self.page_items.clear();
+
+        let Some(per_page) = self.refill else {
+            let items = self.inner.next_page().await?;
+            let pred = &mut self.pred;
+            self.page_items
+                .extend(items.iter().filter(|item| pred(item)).cloned());
+
+            return Some(self.page_items());
+        };
+
+        while self.buffer.len() < per_page && !self.inner_exhausted {
+            match self.inner.next_page().await {
+                Some(items) => {
+                    let pred = &mut self.pred;
+                    self.buffer
+                        .extend(items.iter().filter(|item| pred(item)).cloned());
+                }
+                None => self.inner_exhausted = true,
+            }
+        }
+
+        let len = per_page.min(self.buffer.len());
+        self.page_items.extend(self.buffer.drain(..len));
+
+        if self.page_items.is_empty() {
+            None
+        } else {
+            Some(self.page_items())
+        }
+    }
+
+    async fn skip_page(&mut self) {
+        if self.refill.is_some() {
+            _ = self.next_page().await;
+        } else {
+            self.inner.skip_page().await;
+        }
+
+        self.page_items.clear();
+    }
+
+    #[inline]
+    fn page_items(&self) -> &[E] {
+        &self.page_items
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_pagable() {
+        // Last page is partial
+        let mut pages = InMemoryPagable::new((0..7).collect::<Vec<u32>>(), 3);
+
+        assert_eq!(&[0, 1, 2], pages.next_page().await.unwrap());
+        assert!(!pages.is_exhausted());
+        pages.skip_page().await;
+        assert!(pages.page_items().is_empty());
+        assert_eq!(&[6], pages.next_page().await.unwrap());
+        assert!(pages.is_exhausted());
+        assert!(pages.next_page().await.is_none());
+
+        // Last page is exactly full
+        let mut pages = InMemoryPagable::new((0..4).collect::<Vec<u32>>(), 2);
+
+        assert_eq!(&[0, 1], pages.next_page().await.unwrap());
+        assert_eq!(&[2, 3], pages.next_page().await.unwrap());
+        assert!(!pages.is_exhausted());
+        assert!(pages.next_page().await.is_none());
+        assert!(pages.is_exhausted());
+
+        // Zero-sized pages are empty and don't consume the items
+        let mut pages = InMemoryPagable::new((0..4).collect::<Vec<u32>>(), 0);
+
+        assert!(pages.next_page().await.is_none());
+        assert!(!pages.is_exhausted());
+
+        pages.set_per_page(5);
+        assert_eq!(&[0, 1, 2, 3], pages.next_page().await.unwrap());
+        assert!(pages.is_exhausted());
+    }
+
+    #[tokio::test]
+    async fn test_pagable_take() {
+        let pagable = InMemoryPagable::new((0..20).collect::<Vec<u32>>(), 5);
+
+        let mut pages = pagable.take(12);
+
+        assert_eq!(&[0, 1, 2, 3, 4], pages.next_page().await.unwrap());
+        assert_eq!(&[5, 6, 7, 8, 9], pages.next_page().await.unwrap());
+        assert_eq!(&[10, 11], pages.next_page().await.unwrap());
+        assert_eq!(&[10, 11], pages.page_items());
+        assert!(pages.next_page().await.is_none());
+        assert!(pages.page_items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pagable_and_then_page() {
+        let pagable = InMemoryPagable::new((0..12).collect::<Vec<u32>>(), 5);
+
+        let mut calls: Vec<Vec<u32>> = vec![];
+
+        {
+            let mut pages = pagable.and_then_page(|items| {
+                calls.push(items.to_vec());
+                Box::pin(async move { items.iter().map(|i| i.to_string()).collect() })
+            });
+
+            assert_eq!(&["0", "1", "2", "3", "4"], pages.next_page().await.unwrap());
+            pages.skip_page().await;
+            assert_eq!(&["10", "11"], pages.next_page().await.unwrap());
+            assert!(pages.next_page().await.is_none());
+        }
+
+        // Called once per fetched page with the whole page
+        assert_eq!(vec![vec![0, 1, 2, 3, 4], vec![10, 11]], calls);
+    }
+
+    #[tokio::test]
+    async fn test_pagable_filter_page() {
+        let is_even = |item: &u32| item % 2 == 0;
+
+        // Page boundaries are preserved
+        let mut pages = InMemoryPagable::new((0..10).collect::<Vec<u32>>(), 4).filter_page(is_even);
+
+        assert_eq!(&[0, 2], pages.next_page().await.unwrap());
+        assert_eq!(&[4, 6], pages.next_page().await.unwrap());
+        assert_eq!(&[8], pages.next_page().await.unwrap());
+        assert!(pages.next_page().await.is_none());
+
+        // Pages are refilled
+        let mut pages = InMemoryPagable::new((0..10).collect::<Vec<u32>>(), 4)
+            .filter_page(is_even)
+            .with_refill(4);
+
+        assert_eq!(&[0, 2, 4, 6], pages.next_page().await.unwrap());
+        assert_eq!(&[8], pages.next_page().await.unwrap());
+        assert!(pages.next_page().await.is_none());
+
+        // Skipped pages are filtered as well
+        let mut pages = InMemoryPagable::new((0..20).collect::<Vec<u32>>(), 3)
+            .filter_page(is_even)
+            .with_refill(2);
+
+        pages.skip_page().await;
+        assert!(pages.page_items().is_empty());
+        assert_eq!(&[4, 6], pages.next_page().await.unwrap());
+    }
 }
+
+// endregion: --- Tests
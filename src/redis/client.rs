@@ -42,14 +42,71 @@
 //! }
 //! ```
 
-use super::Result;
-use crate::redis::{collector::AsRedisPairs, RedisModel, RedisRead};
+use super::{Error, Result};
+#[cfg(feature = "compression")]
+use crate::redis::compression::{compress, decompress, Codec};
+use crate::redis::{
+    collector::AsRedisPairs, prefixed_key, scan::PagableRedisScan, validate_key, validate_ttl,
+    FlushConfirm, RedisKeyType, RedisModel, RedisRead,
+};
 use deadpool_redis::{
-    redis::{AsyncCommands, Expiry, ToRedisArgs},
-    Config, Connection, Pool,
+    redis::{
+        cmd, pipe, AsyncCommands, AsyncConnectionConfig, Expiry, FromRedisValue, RedisResult,
+        Script, ToRedisArgs, Value,
+    },
+    Config, Connection, ConnectionInfo, Pool, Timeouts,
 };
-use futures::future::join_all;
+use futures::future::{join_all, BoxFuture};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Lua script used by `Client::rotate`.
+///
+/// Reads the old value, stores the new one (with an optional TTL in seconds) and returns the
+/// old value. A missing key is returned as `nil`.
+const ROTATE_SCRIPT: &str = r#"
+local old = redis.call('GET', KEYS[1])
+if ARGV[2] then
+    redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+else
+    redis.call('SET', KEYS[1], ARGV[1])
+end
+return old
+"#;
+
+/// Lua script used by `Client::decr_floor`.
+///
+/// Decrements the value by `ARGV[1]`, clamping the result at `ARGV[2]`, and returns the new
+/// value. A missing key is treated as 0. The TTL of the key is kept.
+///
+/// Lua numbers are doubles, so the value is checked to be an integer and decremented with
+/// `DECRBY`, keeping the arithmetic exact and rejecting the same values as `DECRBY`. The
+/// reply of `DECRBY` is converted to a Lua number, so the new value is read back with `GET`
+/// to be returned exactly.
+const DECR_FLOOR_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if current and not string.match(current, '^-?%d+$') then
+    return redis.error_reply('ERR value is not an integer or out of range')
+end
+local value = redis.call('DECRBY', KEYS[1], ARGV[1])
+if value < tonumber(ARGV[2]) then
+    redis.call('SET', KEYS[1], ARGV[2], 'KEEPTTL')
+    return ARGV[2]
+end
+return redis.call('GET', KEYS[1])
+"#;
+
+/// Default maximum number of pairs sent in a single `MSET` command by `Client::mset`.
+const DEFAULT_MSET_CHUNK_SIZE: usize = 500;
+
+/// Time added to the timeout of a blocking command to get the response timeout of its
+/// dedicated connection, so the server replies before the client gives up.
+const BLOCKING_RESPONSE_MARGIN: Duration = Duration::from_secs(1);
 
 /// A Redis client for managing connections to a Redis database.
 ///
@@ -61,6 +118,16 @@ use std::fmt::Debug;
 ///
 /// * `pool` - A connection pool that manages the Redis connections. This pool allows for
 ///   concurrent access to the Redis database, improving performance and resource utilization.
+/// * `slow_acquire` - Optional threshold for connection acquisition; slower acquisitions are
+///   reported with a `tracing::warn!`.
+/// * `slow_command` - Optional threshold for command execution; slower commands are reported
+///   with a `tracing::warn!`.
+/// * `pool_get_timeout` - Optional maximum time to wait for a connection from the pool.
+/// * `compression` - Optional codec used to compress values written by `set`, `set_ex`,
+///   `set_get` and `set_many`. Requires the `compression` feature.
+/// * `mset_chunk_size` - Maximum number of pairs sent in a single `MSET` command by `mset`.
+/// * `blocking_client` - Optional client opening the dedicated connections of blocking commands
+///   (`blpop`, `brpop`). Set by `connect`, `None` for clients created with `from_pool`.
 ///
 /// # Implementations
 ///
@@ -86,6 +153,13 @@ use std::fmt::Debug;
 #[derive(Debug, Clone)]
 pub struct Client {
     pool: Pool,
+    slow_acquire: Option<Duration>,
+    slow_command: Option<Duration>,
+    pool_get_timeout: Option<Duration>,
+    #[cfg(feature = "compression")]
+    compression: Option<Codec>,
+    mset_chunk_size: usize,
+    blocking_client: Option<deadpool_redis::redis::Client>,
 }
 
 // Constructors
@@ -116,7 +190,16 @@ impl Client {
     ///
     /// A `Client` instance initialized with the provided pool.
     pub fn from_pool(pool: Pool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            slow_acquire: None,
+            slow_command: None,
+            pool_get_timeout: None,
+            #[cfg(feature = "compression")]
+            compression: None,
+            mset_chunk_size: DEFAULT_MSET_CHUNK_SIZE,
+            blocking_client: None,
+        }
     }
 
     /// Creates a new `Client` instance by connecting to Redis at the specified URL.
@@ -153,198 +236,288 @@ impl Client {
     pub async fn connect(config: &Config) -> Result<Self> {
         let pool = config.create_pool(Some(deadpool_redis::Runtime::Tokio1))?;
 
-        Ok(Self { pool })
+        // `create_pool` rejects a config setting both the URL and the connection info
+        let blocking_client = match (&config.url, &config.connection) {
+            (Some(url), _) => deadpool_redis::redis::Client::open(url.as_str())?,
+            (None, Some(connection)) => deadpool_redis::redis::Client::open(connection.clone())?,
+            (None, None) => deadpool_redis::redis::Client::open(ConnectionInfo::default())?,
+        };
+
+        Ok(Self {
+            blocking_client: Some(blocking_client),
+            ..Self::from_pool(pool)
+        })
     }
 
     /// Retrieves a connection from the connection pool.
     ///
     /// This asynchronous method fetches a connection from the pool associated with the `Client`.
     /// It returns a `Result` containing the `Connection` or an error if the retrieval fails.
+    /// If a pool get timeout is configured with `with_pool_get_timeout`, waiting longer than the
+    /// timeout fails with an error for which `Error::is_pool_timeout` returns `true`.
     ///
     /// # Returns
     ///
     /// A `Result<Connection>` where `Connection` is the retrieved connection from the pool.
     pub async fn connection(&self) -> Result<Connection> {
-        Ok(self.pool.get().await?)
+        let started = Instant::now();
+        let connection = match self.pool_get_timeout {
+            Some(timeout) => {
+                let timeouts = Timeouts {
+                    wait: Some(timeout),
+                    ..self.pool.timeouts()
+                };
+                self.pool.timeout_get(&timeouts).await?
+            }
+            None => self.pool.get().await?,
+        };
+
+        if let Some(threshold) = self.slow_acquire {
+            let elapsed = started.elapsed();
+            if elapsed > threshold {
+                warn!(
+                    "Slow connection acquire: waited {:?} (threshold {:?})",
+                    elapsed, threshold
+                );
+            }
+        }
+
+        Ok(connection)
     }
-}
 
-// Get
-impl Client {
-    /// Asynchronously retrieves a value from Redis using the provided key.
+    /// Runs several commands on a single connection from the pool.
     ///
-    /// This method fetches the value associated with the specified key from Redis. If the key exists, it returns
-    /// the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
+    /// Every `Client` method checks out its own connection, so running several operations
+    /// in a row pays the pool overhead each time. This method checks out one connection and
+    /// passes it to the closure, which can perform any number of commands on it. The
+    /// connection is returned to the pool once the closure completes.
     ///
     /// # Arguments
     ///
-    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved.
+    /// * `f` - A closure receiving the connection and returning a boxed future with the result.
     ///
     /// # Returns
     ///
-    /// A `Result` containing an `Option<V>`, where `Some(value)` is the deserialized value if the key exists,
-    /// or `None` if the key does not exist.
+    /// A `Result<T>` with the value produced by the closure.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
-    /// # use grapple_db::redis::macros::FromRedisValue;
-    /// # use serde::{Serialize, Deserialize};
-    ///
-    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
-    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
-    /// # struct MyValue {
-    /// #     a: u64,
-    /// # }
+    /// use grapple_db::redis::AsyncCommands;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let key = "some_key";
-    ///     let result: Option<MyValue> = client.get(key).await?;
-    ///
-    ///     if let Some(value) = result {
-    ///         println!("Retrieved value: {:?}", value);
-    ///     } else {
-    ///         println!("No value found for key: {}", key);
-    ///     }
+    ///     let value: Option<String> = client
+    ///         .with_connection(|connection| {
+    ///             Box::pin(async move {
+    ///                 let _: () = connection.set("key", "value").await?;
+    ///                 let value = connection.get("key").await?;
+    ///                 let _: () = connection.del("key").await?;
+    ///                 Ok(value)
+    ///             })
+    ///         })
+    ///         .await?;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get<V, K>(&self, key: K) -> Result<Option<V>>
+    pub async fn with_connection<F, T>(&self, f: F) -> Result<T>
     where
-        V: RedisRead,
-        K: for<'a> ToRedisArgs + Send + Sync,
+        F: for<'c> FnOnce(&'c mut Connection) -> BoxFuture<'c, Result<T>>,
     {
         let mut connection = self.connection().await?;
-        Ok(connection.get(key).await?)
+        f(&mut connection).await
     }
+}
 
-    /// Asynchronously retrieves multiple values from Redis using the provided keys.
+// Setters
+impl Client {
+    /// Sets a threshold for connection acquisition time.
     ///
-    /// This method fetches the values associated with the specified keys from Redis. It returns a vector of `Option<V>`,
-    /// where each `Option` contains the deserialized value if the corresponding key exists, or `None` if it does not.
-    /// The type `V` must implement the `FromRedisValue` trait.
+    /// When acquiring a connection from the pool takes longer than `threshold`, a
+    /// `tracing::warn!` is emitted with the measured wait. This helps to detect pool starvation.
     ///
     /// # Arguments
     ///
-    /// * `keys` - An iterable collection of string slices representing the keys for which the values are to be retrieved.
+    /// * `threshold` - The maximum acquisition time before a warning is emitted.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `Vec<Option<V>>`, where each element corresponds to a key in the input collection,
-    /// with `Some(value)` for existing keys and `None` for non-existing keys.
+    /// The client instance with the configured threshold (builder pattern).
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
-    /// # use grapple_db::redis::macros::FromRedisValue;
-    /// # use serde::{Serialize, Deserialize};
-    ///
-    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
-    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
-    /// # struct MyValue {
-    /// #     a: u64,
-    /// # }
+    /// use std::time::Duration;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::default().await?;
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .warn_slow_acquire(Duration::from_millis(50));
     ///
-    ///     let keys = vec!["key1", "key2", "key3"];
-    ///     let results: Vec<Option<MyValue>> = client.mget(&keys).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn warn_slow_acquire(mut self, threshold: Duration) -> Self {
+        self.slow_acquire = Some(threshold);
+        self
+    }
+
+    /// Sets a threshold for command execution time.
     ///
-    ///     for (key, value) in keys.iter().zip(results) {
-    ///         match value {
-    ///             Some(v) => println!("Retrieved value for {}: {:?}", key, v),
-    ///             None => println!("No value found for key: {}", key),
-    ///         }
-    ///     }
+    /// When a command takes longer than `threshold` to complete, a `tracing::warn!` is emitted
+    /// with the command name and the measured duration. Connection acquisition is not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The maximum command duration before a warning is emitted.
+    ///
+    /// # Returns
+    ///
+    /// The client instance with the configured threshold (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .warn_slow_command(Duration::from_millis(100));
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn mget<K, T, V>(&self, keys: K) -> Result<Vec<Option<V>>>
-    where
-        V: RedisRead,
-        K: IntoIterator<Item = T> + ToRedisArgs + Send + Sync,
-        T: for<'a> ToRedisArgs + Send + Sync,
-    {
-        let mut connection = self.connection().await?;
-        Ok(connection.mget(keys).await?)
+    pub fn warn_slow_command(mut self, threshold: Duration) -> Self {
+        self.slow_command = Some(threshold);
+        self
     }
 
-    /// Asynchronously retrieves a value from Redis using the provided key and sets an expiration time.
+    /// Sets the maximum time to wait for a connection from the pool.
     ///
-    /// This method fetches the value associated with the specified key from Redis and sets an expiration time for that key.
-    /// If the key exists, it returns the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
-    /// The `expire_at` parameter specifies when the key should expire.
+    /// Without a timeout, `connection()` waits until a connection becomes available, which may
+    /// hang forever when the pool is exhausted. With a timeout, it fails fast with a pool timeout
+    /// error instead. The pool must be created with a runtime, which is always the case for
+    /// clients created with `default`, `from_url` or `connect`.
     ///
     /// # Arguments
     ///
-    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved.
-    /// * `expire_at` - An `Expiry` value indicating when the key should expire.
+    /// * `timeout` - The maximum time to wait for a connection.
     ///
     /// # Returns
     ///
-    /// A `Result` containing an `Option<V>`, where `Some(value)` is the deserialized value if the key exists,
-    /// or `None` if the key does not exist.
+    /// The client instance with the configured timeout (builder pattern).
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
-    /// # use grapple_db::redis::Expiry;
-    /// # use grapple_db::redis::macros::FromRedisValue;
-    /// # use serde::{Serialize, Deserialize};
+    /// use std::time::Duration;
     ///
-    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
-    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
-    /// # struct MyValue {
-    /// #     a: u64,
-    /// # }
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .with_pool_get_timeout(Duration::from_secs(1));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_pool_get_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_get_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the codec used to compress stored values.
+    ///
+    /// Values written by `set`, `set_ex`, `set_nx`, `set_get`, `getset`, `set_many` and
+    /// `rotate` are compressed and marked with a magic prefix. All other write methods, such as
+    /// `mset`, `mset_nx`, `mset_nx_conflicts`, `set_bytes` and `push_capped`, store values as
+    /// they are. Methods reading values as `V` (`get`, `get_or`, `get_model`, `mget`,
+    /// `mget_map`, `mget_pairs`, `mget_concurrent`, `pipe_get`, `get2`, `get_with_ttl`,
+    /// `get_ex`, `get_del`, `blpop`, `brpop`, ...) decompress them whenever they carry the
+    /// prefix, so keys written with and without compression can be read by any client.
+    /// `get_bytes` returns the stored bytes as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The compression algorithm to use.
+    ///
+    /// # Returns
+    ///
+    /// The client instance with compression enabled (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::{Client, Codec};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::default().await?;
+    ///     let client = Client::default().await?.with_compression(Codec::Gzip);
     ///
-    ///     let key = "some_key";
-    ///     let expire_at = Expiry::EX(60); // Set expiration to 60 seconds
-    ///     let result: Option<MyValue> = client.get_ex(key, expire_at).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, codec: Codec) -> Self {
+        self.compression = Some(codec);
+        self
+    }
+
+    /// Sets the maximum number of pairs sent in a single `MSET` command by `mset`.
     ///
-    ///     if let Some(value) = result {
-    ///         println!("Retrieved value: {:?}", value);
-    ///     } else {
-    ///         println!("No value found for key: {}", key);
-    ///     }
+    /// Larger batches are split into several `MSET` commands sent in one pipeline, so a huge
+    /// batch doesn't exceed protocol limits or block the server. Defaults to 500 pairs.
+    /// A size of 0 is treated as 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The maximum number of pairs per `MSET` command.
+    ///
+    /// # Returns
+    ///
+    /// The client instance with the chunk size set (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?.with_mset_chunk_size(1000);
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get_ex<V, K>(&self, key: K, expire_at: Expiry) -> Result<Option<V>>
-    where
-        V: RedisRead,
-        K: for<'a> ToRedisArgs + Send + Sync,
-    {
-        let mut connection = self.connection().await?;
-        Ok(connection.get_ex(key, expire_at).await?)
+    pub fn with_mset_chunk_size(mut self, size: usize) -> Self {
+        self.mset_chunk_size = size.max(1);
+        self
     }
+}
 
-    /// Asynchronously retrieves a value from Redis using the provided key and deletes the key.
+// Get
+impl Client {
+    /// Asynchronously retrieves a value from Redis using the provided key.
     ///
-    /// This method fetches the value associated with the specified key from Redis and deletes the key in the process.
-    /// If the key exists, it returns the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
+    /// This method fetches the value associated with the specified key from Redis. If the key exists, it returns
+    /// the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
+    ///
+    /// Models are built with `FromRedisValue`, not `RedisModel::migrate`, so values stored in an
+    /// older format fail to deserialize. Use `get_model` to read models that override `migrate`.
     ///
     /// # Arguments
     ///
-    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved and deleted.
+    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved.
     ///
     /// # Returns
     ///
@@ -370,10 +543,10 @@ impl Client {
     ///     let client = Client::default().await?;
     ///
     ///     let key = "some_key";
-    ///     let result: Option<MyValue> = client.get_del(key).await?;
+    ///     let result: Option<MyValue> = client.get(key).await?;
     ///
     ///     if let Some(value) = result {
-    ///         println!("Retrieved and deleted value: {:?}", value);
+    ///         println!("Retrieved value: {:?}", value);
     ///     } else {
     ///         println!("No value found for key: {}", key);
     ///     }
@@ -381,499 +554,541 @@ impl Client {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get_del<V, K>(&self, key: K) -> Result<Option<V>>
+    pub async fn get<V, K>(&self, key: K) -> Result<Option<V>>
     where
         V: RedisRead,
         K: for<'a> ToRedisArgs + Send + Sync,
     {
+        validate_key(&key)?;
+
         let mut connection = self.connection().await?;
-        Ok(connection.get_del(key).await?)
+        let value: Value = self.timed("GET", connection.get(key)).await?;
+
+        decode(value)
     }
 
+    /// Asynchronously retrieves a model from Redis, upgrading values stored in an older format.
+    ///
+    /// This method works like `get`, but builds the model with `RedisModel::migrate` instead of
+    /// `FromRedisValue`, so models whose shape has changed can still read values written by
+    /// previous versions. Compressed values are decompressed before being migrated.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key for which the model is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<M>`, where `Some(model)` is the migrated model if the key
+    /// exists, or `None` if the key does not exist. A value that `migrate` can't convert is
+    /// reported as an error.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// use grapple_db::redis::{Client, RedisModel, Result};
+    /// use serde::{Deserialize, Serialize};
     ///
-    /// #[derive(Debug, Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     id: String,
+    ///     full_name: String,
     /// }
     ///
-    /// impl RedisModel for MyModel {
+    /// #[derive(Deserialize)]
+    /// struct UserV1 {
+    ///     id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// impl RedisModel for User {
     ///     type Key = String;
     ///     type Value = String;
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
+    ///     fn key(&self) -> Result<Self::Key> {
+    ///         Ok(self.id.clone())
     ///     }
     ///
     ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
+    ///         &self.id
     ///     }
     ///
     ///     fn value_ref(&self) -> &Self::Value {
     ///         static PLACEHOLDER: String = String::new();
     ///         &PLACEHOLDER
     ///     }
+    ///
+    ///     fn migrate(raw: &[u8]) -> Result<Self> {
+    ///         if let Ok(user) = serde_json::from_slice(raw) {
+    ///             return Ok(user);
+    ///         }
+    ///
+    ///         let old: UserV1 = serde_json::from_slice(raw)?;
+    ///         Ok(User { id: old.id, full_name: old.name })
+    ///     }
     /// }
     ///
     /// #[tokio::main]
-    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model = MyModel { a: 42 };
-    ///     let old_value: Option<MyModel> = client.getset(&model).await?;
+    ///
+    ///     let user: Option<User> = client.get_model("user:42").await?;
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn getset<M, V>(&self, model: &M) -> Result<Option<V>>
+    pub async fn get_model<M, K>(&self, key: K) -> Result<Option<M>>
     where
-        M: RedisModel,
-        V: RedisRead,
+        M: RedisModel + DeserializeOwned,
+        K: for<'a> ToRedisArgs + Send + Sync,
     {
+        validate_key(&key)?;
+
         let mut connection = self.connection().await?;
-        Ok(connection.getset(model.key()?, model.value()?).await?)
+        let value: Option<Vec<u8>> = self.timed("GET", connection.get(key)).await?;
+
+        #[cfg(feature = "compression")]
+        let value = value.map(decompress).transpose()?;
+
+        value.map(|bytes| M::migrate(&bytes)).transpose()
     }
-}
 
-// Set
-impl Client {
-    /// # Examples
+    /// Asynchronously retrieves a value from Redis, falling back to a default.
     ///
-    /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// This method works like `get`, but returns `default` when the key doesn't exist, which
+    /// suits configuration values with defaults. Only a missing key falls back to the default:
+    /// a stored value that can't be converted into `V` is reported as an error.
     ///
-    /// #[derive(Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
-    /// }
+    /// # Arguments
     ///
-    /// impl RedisModel for MyModel {
-    ///     type Key = String;
-    ///     type Value = String;
+    /// * `key` - The key whose value is to be retrieved.
+    /// * `default` - The value returned when the key doesn't exist.
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
-    ///     }
+    /// # Returns
     ///
-    ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
+    /// A `Result` containing the stored value, or `default` if the key doesn't exist.
     ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
-    ///     }
+    /// # Examples
     ///
-    ///     fn value_ref(&self) -> &Self::Value {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    /// }
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model = MyModel { a: 42 };
-    ///     let result: String = client.set(&model).await?;
+    ///
+    ///     let page_size: u32 = client.get_or("config:page_size", 20).await?;
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn set<M>(&self, model: &M) -> Result<String>
+    pub async fn get_or<V, K>(&self, key: K, default: V) -> Result<V>
     where
-        M: RedisModel,
+        V: FromRedisValue,
+        K: for<'a> ToRedisArgs + Send + Sync,
     {
+        validate_key(&key)?;
+
         let mut connection = self.connection().await?;
-        Ok(connection.set(model.key()?, model.value()?).await?)
+        let value: Value = self.timed("GET", connection.get(key)).await?;
+
+        match value {
+            Value::Nil => Ok(default),
+            value => decode(value),
+        }
     }
 
-    /// # Examples
+    /// Asynchronously retrieves multiple values from Redis using the provided keys.
     ///
-    /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// This method fetches the values associated with the specified keys from Redis. It returns a vector of `Option<V>`,
+    /// where each `Option` contains the deserialized value if the corresponding key exists, or `None` if it does not.
+    /// The type `V` must implement the `FromRedisValue` trait.
     ///
-    /// #[derive(Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
-    /// }
+    /// # Arguments
     ///
-    /// impl RedisModel for MyModel {
-    ///     type Key = String;
-    ///     type Value = String;
+    /// * `keys` - An iterable collection of string slices representing the keys for which the values are to be retrieved.
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
-    ///     }
+    /// # Returns
     ///
-    ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
+    /// A `Result` containing a `Vec<Option<V>>`, where each element corresponds to a key in the input collection,
+    /// with `Some(value)` for existing keys and `None` for non-existing keys.
+    /// An empty collection of keys returns an empty vector without contacting the server.
     ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
-    ///     }
+    /// # Examples
     ///
-    ///     fn value_ref(&self) -> &Self::Value {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    /// }
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    /// # use grapple_db::redis::macros::FromRedisValue;
+    /// # use serde::{Serialize, Deserialize};
+    ///
+    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
+    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
+    /// # struct MyValue {
+    /// #     a: u64,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model1 = MyModel { a: 1 };
-    ///     let model2 = MyModel { a: 2 };
     ///
-    ///     // Используем кортежи для mset
-    ///     let tuple1 = (model1.key().unwrap(), serde_json::to_string(&model1).unwrap());
-    ///     let tuple2 = (model2.key().unwrap(), serde_json::to_string(&model2).unwrap());
-    ///     let result: String = client.mset([&tuple1, &tuple2]).await?;
+    ///     let keys = vec!["key1", "key2", "key3"];
+    ///     let results: Vec<Option<MyValue>> = client.mget(&keys).await?;
+    ///
+    ///     for (key, value) in keys.iter().zip(results) {
+    ///         match value {
+    ///             Some(v) => println!("Retrieved value for {}: {:?}", key, v),
+    ///             None => println!("No value found for key: {}", key),
+    ///         }
+    ///     }
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn mset<M, P>(&self, pairs: P) -> Result<String>
+    pub async fn mget<K, T, V>(&self, keys: K) -> Result<Vec<Option<V>>>
     where
-        M: RedisModel,
-        P: AsRedisPairs<M> + Send + Sync,
+        V: RedisRead,
+        K: IntoIterator<Item = T> + ToRedisArgs + Send + Sync,
+        T: for<'a> ToRedisArgs + Send + Sync,
     {
-        let mut connection = self.connection().await?;
+        let keys: Vec<T> = keys.into_iter().collect();
 
-        // Получаем пары ссылок
-        let pairs = pairs.as_pairs();
+        // Nothing to read, skip the round-trip
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
 
-        // Redis::mset принимает &[(&K, &V)]
-        Ok(connection.mset(&pairs).await?)
+        let mut connection = self.connection().await?;
+        let values: Value = self.timed("MGET", connection.mget(&keys)).await?;
+
+        decode(values)
     }
 
-    /// # Examples
+    /// Asynchronously retrieves multiple values from Redis with parallel `GET` commands.
     ///
-    /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// A single `MGET` can't be used when the keys live on different cluster shards. This method
+    /// sends one `GET` per key, running at most `concurrency` of them at once so the pool isn't
+    /// exhausted. Values are returned in the order of the keys, regardless of the order in which
+    /// the commands complete. A `concurrency` of 0 is treated as 1.
     ///
-    /// #[derive(Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
-    /// }
+    /// # Arguments
     ///
-    /// impl RedisModel for MyModel {
-    ///     type Key = String;
-    ///     type Value = String;
+    /// * `keys` - An iterable collection of keys for which the values are to be retrieved.
+    /// * `concurrency` - The maximum number of commands running at once.
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
-    ///     }
+    /// # Returns
     ///
-    ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
+    /// A `Result` containing a `Vec<Option<V>>`, where each element corresponds to a key in the input
+    /// collection. If any command fails, the first error is returned.
     ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
-    ///     }
+    /// # Examples
     ///
-    ///     fn value_ref(&self) -> &Self::Value {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    /// }
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model1 = MyModel { a: 1 };
-    ///     let model2 = MyModel { a: 2 };
     ///
-    ///     // Используем кортежи для mset_nx
-    ///     let tuple1 = (model1.key().unwrap(), serde_json::to_string(&model1).unwrap());
-    ///     let tuple2 = (model2.key().unwrap(), serde_json::to_string(&model2).unwrap());
-    ///     let result: bool = client.mset_nx([&tuple1, &tuple2]).await?;
+    ///     let keys = vec!["key1", "key2", "key3"];
+    ///     let values: Vec<Option<String>> = client.mget_concurrent(keys, 4).await?;
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn mset_nx<M, P>(&self, pairs: P) -> Result<bool>
+    pub async fn mget_concurrent<K, T, V>(
+        &self,
+        keys: K,
+        concurrency: usize,
+    ) -> Result<Vec<Option<V>>>
     where
-        M: RedisModel,
-        P: AsRedisPairs<M> + Send + Sync,
+        V: RedisRead,
+        K: IntoIterator<Item = T>,
+        T: for<'a> ToRedisArgs + Send + Sync,
     {
-        let mut connection = self.connection().await?;
+        let mut indexed: Vec<(usize, Option<V>)> = stream::iter(keys.into_iter().enumerate())
+            .map(|(index, key)| async move { self.get(key).await.map(|value| (index, value)) })
+            .buffer_unordered(concurrency.max(1))
+            .try_collect()
+            .await?;
 
-        // Получаем пары ссылок без копирования данных
-        let pairs = pairs.as_pairs();
+        // Restore the order of the keys
+        indexed.sort_unstable_by_key(|(index, _)| *index);
 
-        // Redis::mset принимает &[(&K, &V)]
-        Ok(connection.mset_nx(&pairs).await?)
+        Ok(indexed.into_iter().map(|(_, value)| value).collect())
     }
 
-    /// # Examples
+    /// Asynchronously retrieves multiple values from Redis and pairs them with their keys.
     ///
-    /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// This method works like `mget`, but returns each key together with its value. Redis
+    /// guarantees that `MGET` returns one value per key in the same order; the length of the
+    /// response is additionally checked to avoid silently misaligned results.
     ///
-    /// #[derive(Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
-    /// }
+    /// # Arguments
     ///
-    /// impl RedisModel for MyModel {
-    ///     type Key = String;
-    ///     type Value = String;
+    /// * `keys` - A slice of keys for which the values are to be retrieved.
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
-    ///     }
+    /// # Returns
     ///
-    ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
+    /// A `Result` containing a `Vec<(K, Option<V>)>` in the order of the input keys, with
+    /// `Some(value)` for existing keys and `None` for non-existing keys. Returns
+    /// `Error::ResponseLength` if the number of values doesn't match the number of keys.
     ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
-    ///     }
+    /// # Examples
     ///
-    ///     fn value_ref(&self) -> &Self::Value {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    /// }
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model = MyModel { a: 42 };
-    ///     let result: bool = client.set_nx(&model).await?;
+    ///
+    ///     let pairs: Vec<(&str, Option<String>)> = client.mget_pairs(&["key1", "key2"]).await?;
+    ///
+    ///     for (key, value) in pairs {
+    ///         println!("{}: {:?}", key, value);
+    ///     }
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn set_nx<M>(&self, model: &M) -> Result<bool>
+    pub async fn mget_pairs<V, K>(&self, keys: &[K]) -> Result<Vec<(K, Option<V>)>>
     where
-        M: RedisModel,
+        V: RedisRead,
+        K: ToRedisArgs + Clone + Send + Sync,
     {
-        let mut connection = self.connection().await?;
-        Ok(connection.set_nx(model.key()?, model.value()?).await?)
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let values: Vec<Option<V>> = self.mget(keys).await?;
+
+        if values.len() != keys.len() {
+            return Err(Error::ResponseLength {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+
+        Ok(keys.iter().cloned().zip(values).collect())
     }
 
-    /// # Examples
+    /// Asynchronously retrieves multiple values from Redis as a map keyed by key.
     ///
-    /// ```rust,no_run
-    /// use grapple_db::redis;
-    /// use grapple_db::redis::Client;
-    /// use grapple_db::redis::RedisModel;
-    /// use grapple_db::redis::macros::FromRedisValue;
-    /// use serde::{Serialize, Deserialize};
+    /// This method works like `mget`, but returns only the keys that exist, mapped to their
+    /// values, so callers don't have to zip the values with the keys. Duplicate keys collapse
+    /// into a single entry.
     ///
-    /// #[derive(Serialize, Deserialize, FromRedisValue)]
-    /// struct MyModel {
-    ///     a: u64,
-    /// }
+    /// # Arguments
     ///
-    /// impl RedisModel for MyModel {
-    ///     type Key = String;
-    ///     type Value = String;
+    /// * `keys` - A slice of keys for which the values are to be retrieved.
     ///
-    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
-    ///         Ok(self.a.to_string())
-    ///     }
+    /// # Returns
     ///
-    ///     fn key_ref(&self) -> &Self::Key {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
+    /// A `Result` containing a `HashMap<String, V>` with an entry for every existing key.
+    /// Returns `Error::ResponseLength` if the number of values doesn't match the number of keys.
     ///
-    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-    ///         Ok(serde_json::to_string(&self)?)
-    ///     }
+    /// # Examples
     ///
-    ///     fn value_ref(&self) -> &Self::Value {
-    ///         static PLACEHOLDER: String = String::new();
-    ///         &PLACEHOLDER
-    ///     }
-    /// }
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// use std::collections::HashMap;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///     let model = MyModel { a: 42 };
-    ///     let result: String = client.set_ex(&model, 60).await?;
+    ///
+    ///     let values: HashMap<String, String> = client.mget_map(&["key1", "key2"]).await?;
+    ///
+    ///     if let Some(value) = values.get("key1") {
+    ///         println!("key1: {}", value);
+    ///     }
+    ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn set_ex<M>(&self, model: &M, secs: u64) -> Result<String>
+    pub async fn mget_map<V, K>(&self, keys: &[K]) -> Result<HashMap<String, V>>
     where
-        M: RedisModel,
+        V: FromRedisValue,
+        K: ToRedisArgs + AsRef<str> + Send + Sync,
     {
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+
         let mut connection = self.connection().await?;
-        Ok(connection
-            .set_ex(model.key()?, model.value()?, secs)
-            .await?)
+        let values: Value = self.timed("MGET", connection.mget(keys)).await?;
+        let values: Vec<Option<V>> = decode(values)?;
+
+        if values.len() != keys.len() {
+            return Err(Error::ResponseLength {
+                expected: keys.len(),
+                got: values.len(),
+            });
+        }
+
+        Ok(keys
+            .iter()
+            .zip(values)
+            .filter_map(|(key, value)| Some((key.as_ref().to_string(), value?)))
+            .collect())
     }
-}
 
-// Del
-impl Client {
-    /// Asynchronously deletes a key from Redis.
+    /// Asynchronously retrieves multiple values from Redis in a single pipelined round-trip.
     ///
-    /// This method removes the specified key from Redis. If the key exists and is successfully deleted, it returns
-    /// the `true`, if the key does not exist, it returns `false`.
+    /// Unlike `mget`, which issues a single `MGET` command, this method sends one `GET` per key
+    /// inside a pipeline. The results are returned in the same order as the keys. Use
+    /// `Option<V>` as the element type to handle missing keys.
     ///
     /// # Arguments
     ///
-    /// * `key` - The key to be deleted from Redis.
+    /// * `keys` - A slice of keys for which the values are to be retrieved.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `bool`, which indicates if the entity was removed. This will be `true` if
-    /// the key was successfully deleted, or `false` if the key did not exist.
+    /// A `Result` containing a `Vec<V>`, where each element corresponds to a key in the input slice.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let result: bool = client.del("my_key").await?;
+    ///     let values: Vec<Option<String>> = client.pipe_get(&["key1", "key2"]).await?;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn del<K>(&self, key: K) -> Result<bool>
+    pub async fn pipe_get<V, K>(&self, keys: &[K]) -> Result<Vec<V>>
     where
-        K: for<'a> ToRedisArgs + Send + Sync,
+        V: RedisRead,
+        K: ToRedisArgs + Send + Sync,
     {
+        let mut pipeline = pipe();
+        for key in keys {
+            pipeline.get(key);
+        }
+
         let mut connection = self.connection().await?;
-        Ok(connection.del(key).await?)
+        let values: Value = self
+            .timed("PIPELINE", pipeline.query_async(&mut connection))
+            .await?;
+
+        decode(values)
     }
 
-    /// Asynchronously deletes multiple keys from Redis.
+    /// Asynchronously retrieves two values of different types from Redis in a single round-trip.
     ///
-    /// This method removes the specified keys from Redis. It takes an iterable collection of keys and attempts to delete
-    /// each one. The method returns the total number of keys that were successfully removed. If a key does not exist, it
-    /// is simply ignored in the count.
+    /// This method is useful for small heterogeneous fetches, where `mget` is not applicable
+    /// because it deserializes every value into the same type.
     ///
     /// # Arguments
     ///
-    /// * `keys` - An iterable collection of keys to be deleted from Redis.
+    /// * `key1` - The key of the first value.
+    /// * `key2` - The key of the second value.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `usize`, which indicates the number of keys that were successfully removed. This count
-    /// reflects only the keys that existed and were deleted.
+    /// A `Result` containing a tuple `(Option<A>, Option<B>)`, where each element is `None` if
+    /// the corresponding key does not exist.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let deleted_count: usize = client.mdel(vec!["key1", "key2", "key3"]).await?;
+    ///     let (name, age): (Option<String>, Option<i64>) = client.get2("name", "age").await?;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn mdel<K, T>(&self, keys: K) -> Result<usize>
+    pub async fn get2<A, B, K1, K2>(&self, key1: K1, key2: K2) -> Result<(Option<A>, Option<B>)>
     where
-        K: IntoIterator<Item = T>,
-        T: for<'a> ToRedisArgs + Send + Sync,
+        A: RedisRead,
+        B: RedisRead,
+        K1: ToRedisArgs + Send + Sync,
+        K2: ToRedisArgs + Send + Sync,
     {
-        let mut futures = vec![];
-
-        for key in keys {
-            futures.push(self.del(key));
-        }
-
-        // Wait to all operations complete
-        let results = join_all(futures).await;
-
-        // Return count of successfull operations, that returned true
-        Ok(results
-            .iter()
-            .filter(|result| matches!(result, Ok(true)))
-            .count())
+        let mut connection = self.connection().await?;
+        let values: Value = self
+            .timed(
+                "PIPELINE",
+                pipe().get(key1).get(key2).query_async(&mut connection),
+            )
+            .await?;
+
+        decode(values)
     }
-}
 
-// Other
-impl Client {
-    /// Asynchronously checks if a key exists in Redis.
+    /// Asynchronously retrieves a value together with its remaining time-to-live.
     ///
-    /// This method checks whether the specified key is present in Redis. If the key exists, it returns `true`;
-    /// otherwise, it returns `false`.
+    /// This method pipelines `GET` and `TTL` for the key on one connection, so both are read in a
+    /// single round-trip. It is useful for cache-refresh logic deciding whether a value should be
+    /// refreshed soon.
     ///
     /// # Arguments
     ///
-    /// * `key` - The key to check for existence in Redis.
+    /// * `key` - The key for which the value and TTL are to be retrieved.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `bool`, where `true` indicates that the key exists, and `false` indicates that it does not.
+    /// A `Result` containing an `Option<(V, i64)>`. If the key exists, it returns `Some((value, ttl))`
+    /// where `ttl` is the remaining time-to-live in seconds, or `-1` if the key has no expiry.
+    /// If the key does not exist, it returns `None`.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
-    /// # use grapple_db::redis;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let exists: bool = client.exists("my_key").await?;
+    ///     if let Some((value, ttl)) = client.get_with_ttl::<String, _>("my_key").await? {
+    ///         println!("{} expires in {} seconds", value, ttl);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn exists<K>(&self, key: K) -> Result<bool>
+    pub async fn get_with_ttl<V, K>(&self, key: K) -> Result<Option<(V, i64)>>
     where
-        K: for<'a> ToRedisArgs + Send + Sync,
+        V: RedisRead,
+        K: ToRedisArgs + Send + Sync,
     {
         let mut connection = self.connection().await?;
-        Ok(connection.exists(key).await?)
+        let values: Value = self
+            .timed(
+                "PIPELINE",
+                pipe().get(&key).ttl(&key).query_async(&mut connection),
+            )
+            .await?;
+        let (value, ttl): (Option<V>, i64) = decode(values)?;
+
+        Ok(value.map(|value| (value, ttl)))
     }
 
-    /// Asynchronously sends a ping command to Redis to check the connection.
+    /// Asynchronously retrieves the raw bytes stored under the provided key.
     ///
-    /// This method sends a ping command to the Redis server. If the server is reachable and responsive, it returns
-    /// a confirmation message (usually "PONG"). If there is an issue with the connection, an error will be returned.
+    /// This method fetches the value associated with the specified key from Redis without any
+    /// deserialization. Values stored as JSON (e.g. with `set`) are returned as their raw bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key for which the value is to be retrieved.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `String`, which is the response from the Redis server, typically "PONG".
+    /// A `Result` containing an `Option<Vec<u8>>`, where `Some(bytes)` is the stored value if the key
+    /// exists, or `None` if the key does not exist.
     ///
     /// # Examples
     ///
@@ -884,545 +1099,4195 @@ impl Client {
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let response: String = client.ping().await?;
+    ///     if let Some(bytes) = client.get_bytes("avatar:42").await? {
+    ///         println!("Retrieved {} bytes", bytes.len());
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn ping(&self) -> Result<String> {
+    pub async fn get_bytes<K>(&self, key: K) -> Result<Option<Vec<u8>>>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
         let mut connection = self.connection().await?;
-        Ok(connection.ping().await?)
+        self.timed("GET", connection.get(key)).await
     }
 
-    /// Asynchronously renames a key in Redis.
+    /// Asynchronously retrieves a value from Redis using the provided key and sets an expiration time.
     ///
-    /// This method renames the specified key to a new key. If the operation is successful, it returns a confirmation
-    /// message. If the new key already exists, it will be overwritten.
+    /// This method fetches the value associated with the specified key from Redis and sets an expiration time for that key.
+    /// If the key exists, it returns the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
+    /// The `expire_at` parameter specifies when the key should expire.
     ///
     /// # Arguments
     ///
-    /// * `key` - The current key to be renamed.
-    /// * `new_key` - The new key name to assign.
+    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved.
+    /// * `expire_at` - An `Expiry` value indicating when the key should expire.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `String` confirmation message indicating the success of the operation.
+    /// A `Result` containing an `Option<V>`, where `Some(value)` is the deserialized value if the key exists,
+    /// or `None` if the key does not exist.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    /// # use grapple_db::redis::Expiry;
+    /// # use grapple_db::redis::macros::FromRedisValue;
+    /// # use serde::{Serialize, Deserialize};
+    ///
+    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
+    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
+    /// # struct MyValue {
+    /// #     a: u64,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let response: String = client.rename("old_key", "new_key").await?;
+    ///     let key = "some_key";
+    ///     let expire_at = Expiry::EX(60); // Set expiration to 60 seconds
+    ///     let result: Option<MyValue> = client.get_ex(key, expire_at).await?;
+    ///
+    ///     if let Some(value) = result {
+    ///         println!("Retrieved value: {:?}", value);
+    ///     } else {
+    ///         println!("No value found for key: {}", key);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn rename<K1, K2>(&self, key: K1, new_key: K2) -> Result<String>
+    pub async fn get_ex<V, K>(&self, key: K, expire_at: Expiry) -> Result<Option<V>>
     where
-        K1: for<'a> ToRedisArgs + Send + Sync,
-        K2: for<'a> ToRedisArgs + Send + Sync,
+        V: RedisRead,
+        K: for<'a> ToRedisArgs + Send + Sync,
     {
+        validate_key(&key)?;
+
         let mut connection = self.connection().await?;
-        Ok(connection.rename(key, new_key).await?)
+        let value: Value = self
+            .timed("GETEX", connection.get_ex(key, expire_at))
+            .await?;
+
+        decode(value)
     }
 
-    /// Asynchronously renames a key in Redis only if the new key does not already exist.
+    /// Asynchronously retrieves a value from Redis using the provided key and deletes the key.
     ///
-    /// This method attempts to rename the specified key to a new key name, but only if the new key does not already
-    /// exist in Redis. If the operation is successful and the new key was created, it returns `true`. If the new
-    /// key already exists, it does not perform the rename and returns `false`.
+    /// This method fetches the value associated with the specified key from Redis and deletes the key in the process.
+    /// If the key exists, it returns the value deserialized into the type `V`. The type `V` must implement the `FromRedisValue` trait.
     ///
     /// # Arguments
     ///
-    /// * `key` - The current key to be renamed.
-    /// * `new_key` - The new key name to assign.
+    /// * `key` - A reference to a string slice that represents the key for which the value is to be retrieved and deleted.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `bool`, where `true` indicates that the rename was successful, and `false` indicates
-    /// that the new key already existed.
+    /// A `Result` containing an `Option<V>`, where `Some(value)` is the deserialized value if the key exists,
+    /// or `None` if the key does not exist.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    /// # use grapple_db::redis::macros::FromRedisValue;
+    /// # use serde::{Serialize, Deserialize};
+    ///
+    /// // Assuming you have a type defined with trait `FromRedisValue` implemented
+    /// # #[derive(Debug,Serialize, Deserialize, FromRedisValue)]
+    /// # struct MyValue {
+    /// #     a: u64,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///
-    ///     let success: bool = client.rename_nx("old_key", "new_key").await?;
+    ///     let key = "some_key";
+    ///     let result: Option<MyValue> = client.get_del(key).await?;
+    ///
+    ///     if let Some(value) = result {
+    ///         println!("Retrieved and deleted value: {:?}", value);
+    ///     } else {
+    ///         println!("No value found for key: {}", key);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn rename_nx<K1, K2>(&self, key: K1, new_key: K2) -> Result<bool>
+    pub async fn get_del<V, K>(&self, key: K) -> Result<Option<V>>
     where
-        K1: for<'a> ToRedisArgs + Send + Sync,
-        K2: for<'a> ToRedisArgs + Send + Sync,
+        V: RedisRead,
+        K: for<'a> ToRedisArgs + Send + Sync,
     {
-        let mut connection = self.connection().await?;
-        Ok(connection.rename_nx(key, new_key).await?)
-    }
-}
-
-// region:    --- Tests
-
-#[cfg(test)]
-mod tests {
-    type Result<T> = super::Result<T>; // For tests.
+        validate_key(&key)?;
 
-    use std::time::Duration;
+        let mut connection = self.connection().await?;
+        let value: Value = self.timed("GETDEL", connection.get_del(key)).await?;
 
-    use crate::redis;
-    use crate::redis::macros::FromRedisValue;
-    use crate::redis::RedisModel;
-    use serde::{Deserialize, Serialize};
-    use uuid::Uuid;
+        decode(value)
+    }
 
-    use super::*;
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model = MyModel { a: 42 };
+    ///     let old_value: Option<MyModel> = client.getset(&model).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn getset<M, V>(&self, model: &M) -> Result<Option<V>>
+    where
+        M: RedisModel,
+        V: RedisRead,
+    {
+        self.set_get(model).await
+    }
 
-    // region:    --- Tst Struct
+    /// Asynchronously sets a model in Redis and returns the previous value.
+    ///
+    /// This method uses `SET ... GET` to atomically store the model and retrieve the value
+    /// previously stored under its key. It replaces the deprecated `GETSET` command. If the
+    /// model has a TTL (see `RedisModel::ttl_secs`), it is applied with the same command.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - A reference to the model to be stored in Redis.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<V>`. If the key existed, it returns `Some(value)` with
+    /// the previous value; otherwise, it returns `None`. If the key holds a non-string value,
+    /// it returns `Error::WrongType`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let model = ("counter".to_string(), 2);
+    ///     let old_value: Option<i32> = client.set_get(&model).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_get<M, V>(&self, model: &M) -> Result<Option<V>>
+    where
+        M: RedisModel,
+        V: RedisRead,
+    {
+        model.validate_key()?;
 
-    #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
-    struct Tst {
-        key: String,
-        a: u64,
-        b: u64,
-    }
+        let mut command = cmd("SET");
+        command
+            .arg(model.prefixed_key()?)
+            .arg(self.encode(model.value()?)?);
 
-    impl RedisModel for Tst {
-        type Key = String;
-        type Value = String;
+        if let Some(secs) = model.ttl_secs() {
+            validate_ttl(secs)?;
 
-        fn key_ref(&self) -> &Self::Key {
-            &self.key
+            command.arg("EX").arg(secs);
         }
 
-        fn key(&self) -> redis::Result<Self::Key> {
-            Ok(self.key.clone())
-        }
+        command.arg("GET");
 
-        fn value(&self) -> redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
-            Ok(serde_json::to_string(&self)?)
-        }
+        let mut connection = self.connection().await?;
+        let value: Value = self
+            .timed("SET", command.query_async(&mut connection))
+            .await?;
 
-        fn value_ref(&self) -> &Self::Value {
-            // Для тестов нам не нужно реальное значение
-            static PLACEHOLDER: String = String::new();
-            &PLACEHOLDER
+        decode(value)
+    }
+
+    /// Asynchronously replaces a model in Redis and returns the previous value.
+    ///
+    /// This method runs a Lua script that reads the old value, replaces it with the model and
+    /// returns the old value, all atomically on the key. It is intended for rotating single-use
+    /// values such as tokens, where no other client may observe or reuse the old value after it
+    /// was read. If the model has a TTL (see `RedisModel::ttl_secs`), it is applied to the new
+    /// value.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - A reference to the model replacing the stored value.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<V>`. If the key existed, it returns `Some(value)` with
+    /// the previous value; otherwise, it returns `None` and the model is still stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let token = ("token:user:1".to_string(), "new-token".to_string());
+    ///     let old_token: Option<String> = client.rotate(&token).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn rotate<M, V>(&self, new: &M) -> Result<Option<V>>
+    where
+        M: RedisModel,
+        V: FromRedisValue,
+    {
+        new.validate_key()?;
+
+        let script = Script::new(ROTATE_SCRIPT);
+        let mut invocation = script.prepare_invoke();
+        invocation
+            .key(new.prefixed_key()?)
+            .arg(self.encode(new.value()?)?);
+
+        if let Some(secs) = new.ttl_secs() {
+            validate_ttl(secs)?;
+
+            invocation.arg(secs);
+        }
+
+        let mut connection = self.connection().await?;
+        let value: Value = self
+            .timed("EVALSHA", invocation.invoke_async(&mut connection))
+            .await?;
+
+        decode(value)
+    }
+
+    /// Asynchronously pops the first element of a list, blocking until one is available.
+    ///
+    /// This method wraps `BLPOP`, intended for workers consuming a list used as a queue filled with `RPUSH`:
+    /// instead of polling, the call waits on the server until an element is pushed or the timeout
+    /// elapses. Clients created with `connect`, `from_url` or `default` run it on a dedicated
+    /// connection opened for the call, whose response timeout covers the whole wait. Clients
+    /// created with `from_pool` use a pooled connection instead, so waits longer than its
+    /// response timeout fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the list.
+    /// * `timeout` - The maximum time to wait for an element. A zero timeout blocks indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<V>`, where `Some(value)` is the popped element, or `None`
+    /// if the timeout elapsed with no element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     while let Some(job) = client.blpop::<String, _>("jobs", Duration::from_secs(5)).await? {
+    ///         println!("Processing job: {}", job);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn blpop<V, K>(&self, key: K, timeout: Duration) -> Result<Option<V>>
+    where
+        V: FromRedisValue,
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        self.blocking_pop("BLPOP", key, timeout).await
+    }
+
+    /// Asynchronously pops the last element of a list, blocking until one is available.
+    ///
+    /// This method wraps `BRPOP`, intended for workers consuming a list used as a queue filled with `LPUSH`:
+    /// instead of polling, the call waits on the server until an element is pushed or the timeout
+    /// elapses. Clients created with `connect`, `from_url` or `default` run it on a dedicated
+    /// connection opened for the call, whose response timeout covers the whole wait. Clients
+    /// created with `from_pool` use a pooled connection instead, so waits longer than its
+    /// response timeout fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the list.
+    /// * `timeout` - The maximum time to wait for an element. A zero timeout blocks indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<V>`, where `Some(value)` is the popped element, or `None`
+    /// if the timeout elapsed with no element.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     while let Some(job) = client.brpop::<String, _>("jobs", Duration::from_secs(5)).await? {
+    ///         println!("Processing job: {}", job);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn brpop<V, K>(&self, key: K, timeout: Duration) -> Result<Option<V>>
+    where
+        V: FromRedisValue,
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        self.blocking_pop("BRPOP", key, timeout).await
+    }
+}
+
+// Set
+impl Client {
+    /// Asynchronously stores a model in Redis.
+    ///
+    /// If the model defines a default TTL via `RedisModel::ttl_secs`, the value is stored with
+    /// `SETEX` and expires after that many seconds; otherwise it is stored without expiration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model = MyModel { a: 42 };
+    ///     let result: String = client.set(&model).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set<M>(&self, model: &M) -> Result<String>
+    where
+        M: RedisModel,
+    {
+        model.validate_key()?;
+
+        if let Some(secs) = model.ttl_secs() {
+            return self.set_ex(model, secs).await;
+        }
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            "SET",
+            connection.set(model.prefixed_key()?, self.encode(model.value()?)?),
+        )
+        .await
+    }
+
+    /// Asynchronously sets multiple key-value pairs in Redis with `MSET`.
+    ///
+    /// Batches larger than the configured chunk size (500 pairs by default, see
+    /// `with_mset_chunk_size`) are split into several `MSET` commands sent in one pipeline.
+    /// Each chunk is applied atomically, but the batch as a whole is not: the call returns
+    /// `"OK"` only if every chunk succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model1 = MyModel { a: 1 };
+    ///     let model2 = MyModel { a: 2 };
+    ///
+    ///     // Используем кортежи для mset
+    ///     let tuple1 = (model1.key().unwrap(), serde_json::to_string(&model1).unwrap());
+    ///     let tuple2 = (model2.key().unwrap(), serde_json::to_string(&model2).unwrap());
+    ///     let result: String = client.mset([&tuple1, &tuple2]).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn mset<M, P>(&self, pairs: P) -> Result<String>
+    where
+        M: RedisModel,
+        P: AsRedisPairs<M> + Send + Sync,
+    {
+        // Получаем пары ссылок
+        let pairs = pairs.as_pairs();
+
+        // MSET without pairs is a Redis error, treat it as a no-op
+        if pairs.is_empty() {
+            return Ok("OK".to_string());
+        }
+
+        if !M::PREFIX.is_empty() {
+            let pairs = prefixed_pairs::<M>(&pairs);
+            return self.mset_chunked(&pairs).await;
+        }
+
+        // Redis::mset принимает &[(&K, &V)]
+        self.mset_chunked(&pairs).await
+    }
+
+    /// Asynchronously sets multiple key-value pairs in Redis, rejecting duplicate keys.
+    ///
+    /// This method works like `mset`, but first checks the pairs for duplicate keys. `MSET`
+    /// silently keeps the last value for a repeated key, which usually hides a programming
+    /// mistake, so such batches are rejected before reaching Redis.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The key-value pairs to be stored in Redis.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `String` confirmation message, or `Error::DuplicateKeys`
+    /// listing every key that occurs more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let tuple1 = ("key1".to_string(), "value1".to_string());
+    ///     let tuple2 = ("key2".to_string(), "value2".to_string());
+    ///     let result: String = client.mset_checked([&tuple1, &tuple2]).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn mset_checked<M, P>(&self, pairs: P) -> Result<String>
+    where
+        M: RedisModel,
+        P: AsRedisPairs<M> + Send + Sync,
+    {
+        let mut seen = HashSet::new();
+        let mut duplicates = Vec::new();
+
+        for (key, _) in pairs.as_pairs() {
+            let key = key.to_redis_args().concat();
+
+            if !seen.insert(key.clone()) && !duplicates.contains(&key) {
+                duplicates.push(key);
+            }
+        }
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateKeys(
+                duplicates
+                    .iter()
+                    .map(|key| String::from_utf8_lossy(key).into_owned())
+                    .collect(),
+            ));
+        }
+
+        self.mset(pairs).await
+    }
+
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model1 = MyModel { a: 1 };
+    ///     let model2 = MyModel { a: 2 };
+    ///
+    ///     // Используем кортежи для mset_nx
+    ///     let tuple1 = (model1.key().unwrap(), serde_json::to_string(&model1).unwrap());
+    ///     let tuple2 = (model2.key().unwrap(), serde_json::to_string(&model2).unwrap());
+    ///     let result: bool = client.mset_nx([&tuple1, &tuple2]).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn mset_nx<M, P>(&self, pairs: P) -> Result<bool>
+    where
+        M: RedisModel,
+        P: AsRedisPairs<M> + Send + Sync,
+    {
+        // Получаем пары ссылок без копирования данных
+        let pairs = pairs.as_pairs();
+
+        // MSETNX without pairs is a Redis error, treat it as a no-op
+        if pairs.is_empty() {
+            return Ok(true);
+        }
+
+        let mut connection = self.connection().await?;
+
+        if !M::PREFIX.is_empty() {
+            let pairs = prefixed_pairs::<M>(&pairs);
+            return self.timed("MSETNX", connection.mset_nx(&pairs)).await;
+        }
+
+        // Redis::mset принимает &[(&K, &V)]
+        self.timed("MSETNX", connection.mset_nx(&pairs)).await
+    }
+
+    /// Asynchronously sets multiple key-value pairs if none of the keys exist, reporting conflicts.
+    ///
+    /// This method works like `mset_nx`, but when nothing was set because some keys already
+    /// exist, it checks every key with a pipelined `EXISTS` pass and returns the conflicting
+    /// keys, so the caller can decide how to proceed. The keys are returned as stored in Redis,
+    /// i.e. with the model `PREFIX`. Note that a key may be created or deleted between both
+    /// passes, so the report reflects the state at the time of the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - A collection of models (or key-value tuples) to set.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the keys that already existed. An empty vector means all pairs
+    /// were set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let tuple1 = ("user:1".to_string(), "John".to_string());
+    ///     let tuple2 = ("user:2".to_string(), "Jane".to_string());
+    ///
+    ///     let conflicts = client.mset_nx_conflicts([&tuple1, &tuple2]).await?;
+    ///
+    ///     if !conflicts.is_empty() {
+    ///         println!("Already taken: {:?}", conflicts);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn mset_nx_conflicts<M, P>(&self, pairs: P) -> Result<Vec<String>>
+    where
+        M: RedisModel,
+        P: AsRedisPairs<M> + Send + Sync,
+    {
+        let keys: Vec<Vec<u8>> = pairs
+            .as_pairs()
+            .iter()
+            .map(|(key, _)| prefixed_key(M::PREFIX, *key))
+            .collect();
+
+        if self.mset_nx(pairs).await? {
+            return Ok(vec![]);
+        }
+
+        let mut pipeline = pipe();
+
+        for key in &keys {
+            pipeline.exists(key);
+        }
+
+        let mut connection = self.connection().await?;
+        let exists: Vec<bool> = self
+            .timed("PIPELINE", pipeline.query_async(&mut connection))
+            .await?;
+
+        Ok(keys
+            .iter()
+            .zip(exists)
+            .filter(|(_, exists)| *exists)
+            .map(|(key, _)| String::from_utf8_lossy(key).into_owned())
+            .collect())
+    }
+
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model = MyModel { a: 42 };
+    ///     let result: bool = client.set_nx(&model).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_nx<M>(&self, model: &M) -> Result<bool>
+    where
+        M: RedisModel,
+    {
+        model.validate_key()?;
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            "SETNX",
+            connection.set_nx(model.prefixed_key()?, self.encode(model.value()?)?),
+        )
+        .await
+    }
+
+    /// Asynchronously stores a model in Redis with an expiration time in seconds.
+    ///
+    /// The TTL is validated with `validate_ttl` before the command is sent: a TTL of 0 or beyond
+    /// the range supported by Redis is rejected with `Error::InvalidTtl`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis;
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::redis::RedisModel;
+    /// use grapple_db::redis::macros::FromRedisValue;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Serialize, Deserialize, FromRedisValue)]
+    /// struct MyModel {
+    ///     a: u64,
+    /// }
+    ///
+    /// impl RedisModel for MyModel {
+    ///     type Key = String;
+    ///     type Value = String;
+    ///
+    ///     fn key(&self) -> grapple_db::redis::Result<Self::Key> {
+    ///         Ok(self.a.to_string())
+    ///     }
+    ///
+    ///     fn key_ref(&self) -> &Self::Key {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    ///
+    ///     fn value(&self) -> grapple_db::redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+    ///         Ok(serde_json::to_string(&self)?)
+    ///     }
+    ///
+    ///     fn value_ref(&self) -> &Self::Value {
+    ///         static PLACEHOLDER: String = String::new();
+    ///         &PLACEHOLDER
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let model = MyModel { a: 42 };
+    ///     let result: String = client.set_ex(&model, 60).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_ex<M>(&self, model: &M, secs: u64) -> Result<String>
+    where
+        M: RedisModel,
+    {
+        model.validate_key()?;
+        validate_ttl(secs)?;
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            "SETEX",
+            connection.set_ex(model.prefixed_key()?, self.encode(model.value()?)?, secs),
+        )
+        .await
+    }
+
+    /// Asynchronously stores multiple models in Redis using a single pipeline.
+    ///
+    /// Unlike `mset`, this method honors the TTL of each model: models defining a TTL via
+    /// `RedisModel::ttl_secs` are stored with `SETEX`, others with `SET`. All commands are
+    /// sent in one round-trip on a single connection. Keys and TTLs are validated before
+    /// anything is sent, so an invalid model stores nothing. An empty slice returns without
+    /// contacting the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `models` - A slice of references to the models to store.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating whether the models were stored successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     // Tuples of (key, value, ttl) expire after the given number of seconds
+    ///     let session = ("session:1".to_string(), "token".to_string(), 60);
+    ///     let refresh = ("refresh:1".to_string(), "token".to_string(), 3600);
+    ///
+    ///     client.set_many(&[&session, &refresh]).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_many<M>(&self, models: &[&M]) -> Result<()>
+    where
+        M: RedisModel,
+    {
+        if models.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipeline = pipe();
+
+        for model in models {
+            model.validate_key()?;
+
+            let ttl = model.ttl_secs();
+            if let Some(secs) = ttl {
+                validate_ttl(secs)?;
+            }
+
+            match ttl {
+                Some(secs) => pipeline
+                    .set_ex(model.prefixed_key()?, self.encode(model.value()?)?, secs)
+                    .ignore(),
+                None => pipeline
+                    .set(model.prefixed_key()?, self.encode(model.value()?)?)
+                    .ignore(),
+            };
+        }
+
+        let mut connection = self.connection().await?;
+        self.timed("PIPELINE", pipeline.query_async(&mut connection))
+            .await
+    }
+
+    /// Asynchronously stores raw bytes under the provided key.
+    ///
+    /// Unlike `set`, this method doesn't serialize the value, so binary data such as images
+    /// or protobuf messages is stored as is instead of being JSON-encoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key under which the bytes are stored.
+    /// * `bytes` - The raw bytes to store.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating whether the bytes were stored successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let bytes = std::fs::read("avatar.png")?;
+    ///     client.set_bytes("avatar:42", &bytes).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_bytes<K>(&self, key: K, bytes: &[u8]) -> Result<()>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        let mut connection = self.connection().await?;
+        self.timed("SET", connection.set(key, bytes)).await
+    }
+
+    /// Asynchronously appends a value to a JSON array with `JSON.ARRAPPEND`.
+    ///
+    /// The value is serialized to JSON and appended to the array at `path` in a single command,
+    /// so concurrent appends don't race like a read-modify-write of the whole document would.
+    /// It requires the RedisJSON module on the server and the `redis-json` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the JSON document.
+    /// * `path` - The JSONPath of the array, e.g. `$` or `$.events`.
+    /// * `value` - The value to append.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new length of the array. If the path doesn't point to an
+    /// array, it returns `Error::Redis`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let len = client
+    ///         .json_arr_append("activity:42", "$.events", &"logged_in")
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "redis-json")]
+    pub async fn json_arr_append<T>(&self, key: &str, path: &str, value: &T) -> Result<usize>
+    where
+        T: serde::Serialize,
+    {
+        validate_key(&key)?;
+
+        let mut command = cmd("JSON.ARRAPPEND");
+        command
+            .arg(key)
+            .arg(path)
+            .arg(serde_json::to_string(value)?);
+
+        let mut connection = self.connection().await?;
+        let reply: Value = self
+            .timed("JSON.ARRAPPEND", command.query_async(&mut connection))
+            .await?;
+
+        // JSONPath replies hold one length per matched path, legacy paths a single length
+        let length = match reply {
+            Value::Array(lengths) => lengths.into_iter().next().unwrap_or(Value::Nil),
+            length => length,
+        };
+
+        match Option::<usize>::from_redis_value(&length)? {
+            Some(length) => Ok(length),
+            None => Err(Error::Redis(
+                (
+                    deadpool_redis::redis::ErrorKind::TypeError,
+                    "JSON.ARRAPPEND path doesn't point to an array",
+                )
+                    .into(),
+            )),
+        }
+    }
+
+    /// Asynchronously pushes a value to the head of a list, keeping at most `max_len` items.
+    ///
+    /// This method sends `LPUSH` followed by `LTRIM` in a single `MULTI`/`EXEC` pipeline on one
+    /// connection, so the list never holds more than `max_len` items. It is useful for capped
+    /// lists such as "the last 100 events" feeds, where the most recent items come first.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the list.
+    /// * `value` - The value to push.
+    /// * `max_len` - The maximum number of items kept in the list. A value of 0 leaves
+    ///   the list empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating whether the value was pushed successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     client.push_capped("events:recent", "user_logged_in", 100).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn push_capped<K, V>(&self, key: K, value: V, max_len: usize) -> Result<()>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        // LTRIM with a start past the stop empties the list
+        let (start, stop) = match max_len {
+            0 => (1, 0),
+            len => (0, len as isize - 1),
+        };
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            "PIPELINE",
+            pipe()
+                .atomic()
+                .lpush(&key, value)
+                .ignore()
+                .ltrim(&key, start, stop)
+                .ignore()
+                .query_async(&mut connection),
+        )
+        .await
+    }
+
+    /// Asynchronously decrements a counter without letting it go below a floor.
+    ///
+    /// This method runs a Lua script that decrements the counter by `by` and clamps the result
+    /// at `floor`, atomically on the key. It is useful for token-bucket rate limiters, where the
+    /// number of remaining tokens must not become negative. A missing key is treated as 0
+    /// before clamping, and the TTL of an existing key is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the counter.
+    /// * `by` - The amount to decrement by.
+    /// * `floor` - The minimum value of the counter.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the value of the counter after the decrement. If the key holds
+    /// a value that isn't an integer, it returns `Error::Redis`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let remaining = client.decr_floor("tokens:user:1", 1, 0).await?;
+    ///     if remaining == 0 {
+    ///         println!("Rate limited");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn decr_floor<K>(&self, key: K, by: i64, floor: i64) -> Result<i64>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        let script = Script::new(DECR_FLOOR_SCRIPT);
+        let mut invocation = script.prepare_invoke();
+        invocation.key(key).arg(by).arg(floor);
+
+        let mut connection = self.connection().await?;
+        self.timed("EVALSHA", invocation.invoke_async(&mut connection))
+            .await
+    }
+}
+
+// Del
+impl Client {
+    /// Asynchronously deletes a key from Redis.
+    ///
+    /// This method removes the specified key from Redis. If the key exists and is successfully deleted, it returns
+    /// the `true`, if the key does not exist, it returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to be deleted from Redis.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `bool`, which indicates if the entity was removed. This will be `true` if
+    /// the key was successfully deleted, or `false` if the key did not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let result: bool = client.del("my_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn del<K>(&self, key: K) -> Result<bool>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed("DEL", connection.del(key)).await
+    }
+
+    /// Asynchronously deletes multiple keys from Redis.
+    ///
+    /// This method removes the specified keys from Redis. It takes an iterable collection of keys and attempts to delete
+    /// each one. The method returns the total number of keys that were successfully removed. If a key does not exist, it
+    /// is simply ignored in the count.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - An iterable collection of keys to be deleted from Redis.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `usize`, which indicates the number of keys that were successfully removed. This count
+    /// reflects only the keys that existed and were deleted. An empty collection of keys returns `0`
+    /// without contacting the server.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let deleted_count: usize = client.mdel(vec!["key1", "key2", "key3"]).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn mdel<K, T>(&self, keys: K) -> Result<usize>
+    where
+        K: IntoIterator<Item = T>,
+        T: for<'a> ToRedisArgs + Send + Sync,
+    {
+        let mut futures = vec![];
+
+        for key in keys {
+            futures.push(self.del(key));
+        }
+
+        // Wait to all operations complete
+        let results = join_all(futures).await;
+
+        // Return count of successfull operations, that returned true
+        Ok(results
+            .iter()
+            .filter(|result| matches!(result, Ok(true)))
+            .count())
+    }
+}
+
+// Other
+impl Client {
+    /// Asynchronously checks if a key exists in Redis.
+    ///
+    /// This method checks whether the specified key is present in Redis. If the key exists, it returns `true`;
+    /// otherwise, it returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to check for existence in Redis.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `bool`, where `true` indicates that the key exists, and `false` indicates that it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// # use grapple_db::redis;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let exists: bool = client.exists("my_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn exists<K>(&self, key: K) -> Result<bool>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed("EXISTS", connection.exists(key)).await
+    }
+
+    /// Asynchronously waits until a key appears in Redis.
+    ///
+    /// This method polls `EXISTS` every `poll` until the key appears or `timeout` elapses, which
+    /// is useful for waiting on a result written by another process. If the key is already
+    /// present, it returns `true` without waiting. A `poll` interval of 0 is treated as 1
+    /// millisecond. A `timeout` too large to compute a deadline from, such as `Duration::MAX`,
+    /// waits until the key appears.
+    ///
+    /// Polling works for keys of any type. When the producer pushes the result to a list
+    /// instead, prefer blocking with `BLPOP`, which returns as soon as an item is pushed
+    /// without polling, but removes the item from the list (see the example below).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to wait for.
+    /// * `timeout` - The maximum time to wait.
+    /// * `poll` - The interval between two `EXISTS` checks.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the key appeared, or `false` if the timeout elapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::{AsyncCommands, Client};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     // Poll for a result key written by another worker
+    ///     let ready = client
+    ///         .wait_for_key("job:1:result", Duration::from_secs(5), Duration::from_millis(50))
+    ///         .await?;
+    ///
+    ///     // Block until an item is pushed to a list (the item is popped)
+    ///     let item: Option<(String, String)> = client
+    ///         .with_connection(|connection| {
+    ///             Box::pin(async move { Ok(connection.blpop("job:1:results", 5.0).await?) })
+    ///         })
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_for_key(&self, key: &str, timeout: Duration, poll: Duration) -> Result<bool> {
+        let poll = poll.max(Duration::from_millis(1));
+        let deadline = Instant::now().checked_add(timeout);
+
+        loop {
+            if self.exists(key).await? {
+                return Ok(true);
+            }
+
+            let sleep = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(false);
+                    }
+                    poll.min(deadline - now)
+                }
+                None => poll,
+            };
+
+            tokio::time::sleep(sleep).await;
+        }
+    }
+
+    /// Asynchronously sends a ping command to Redis to check the connection.
+    ///
+    /// This method sends a ping command to the Redis server. If the server is reachable and responsive, it returns
+    /// a confirmation message (usually "PONG"). If there is an issue with the connection, an error will be returned.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `String`, which is the response from the Redis server, typically "PONG".
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let response: String = client.ping().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<String> {
+        let mut connection = self.connection().await?;
+        self.timed("PING", connection.ping()).await
+    }
+
+    /// Asynchronously sends a ping command with a message to Redis.
+    ///
+    /// This method sends `PING message` to the Redis server, which echoes the message back. It is
+    /// useful as a connectivity or latency canary carrying a nonce. If the message is empty, a plain
+    /// `PING` is sent and the response is "PONG".
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to be echoed by the server.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `String`, which is the echoed message, or "PONG" for an empty message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let response: String = client.ping_message("hello").await?;
+    ///     assert_eq!("hello", response);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn ping_message(&self, msg: &str) -> Result<String> {
+        if msg.is_empty() {
+            return self.ping().await;
+        }
+
+        let mut connection = self.connection().await?;
+        self.timed("PING", cmd("PING").arg(msg).query_async(&mut connection))
+            .await
+    }
+
+    /// Asynchronously measures the round-trip latency to Redis.
+    ///
+    /// This method times a `PING` round-trip, including acquiring a connection from the pool,
+    /// so the result reflects the latency observed by regular commands. A connection is
+    /// acquired on every call.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the elapsed `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let latency = client.latency().await?;
+    ///     println!("Latency: {:?}", latency);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn latency(&self) -> Result<Duration> {
+        let started = Instant::now();
+
+        let mut connection = self.connection().await?;
+        let _: String = self.timed("PING", connection.ping()).await?;
+
+        Ok(started.elapsed())
+    }
+
+    /// Asynchronously renames a key in Redis.
+    ///
+    /// This method renames the specified key to a new key. If the operation is successful, it returns a confirmation
+    /// message. If the new key already exists, it will be overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The current key to be renamed.
+    /// * `new_key` - The new key name to assign.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `String` confirmation message indicating the success of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let response: String = client.rename("old_key", "new_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn rename<K1, K2>(&self, key: K1, new_key: K2) -> Result<String>
+    where
+        K1: for<'a> ToRedisArgs + Send + Sync,
+        K2: for<'a> ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed("RENAME", connection.rename(key, new_key)).await
+    }
+
+    /// Asynchronously renames a key in Redis only if the new key does not already exist.
+    ///
+    /// This method attempts to rename the specified key to a new key name, but only if the new key does not already
+    /// exist in Redis. If the operation is successful and the new key was created, it returns `true`. If the new
+    /// key already exists, it does not perform the rename and returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The current key to be renamed.
+    /// * `new_key` - The new key name to assign.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `bool`, where `true` indicates that the rename was successful, and `false` indicates
+    /// that the new key already existed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let success: bool = client.rename_nx("old_key", "new_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn rename_nx<K1, K2>(&self, key: K1, new_key: K2) -> Result<bool>
+    where
+        K1: for<'a> ToRedisArgs + Send + Sync,
+        K2: for<'a> ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed("RENAMENX", connection.rename_nx(key, new_key))
+            .await
+    }
+
+    /// Asynchronously sets a key to expire at an absolute Unix timestamp with `EXPIREAT`.
+    ///
+    /// Unlike a relative TTL, the expiration is a wall-clock time, which suits cache entries
+    /// that must expire at a specific moment, e.g. at the end of the day. A timestamp in the
+    /// past deletes the key immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to expire.
+    /// * `unix_secs` - The Unix timestamp in seconds at which the key expires.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the expiration was set (or the key was deleted because
+    /// the timestamp is in the past), or `false` if the key doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     // Expire the daily report at 2030-01-01 00:00:00 UTC
+    ///     let expired: bool = client.expire_at("report:daily", 1_893_456_000).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn expire_at<K>(&self, key: K, unix_secs: i64) -> Result<bool>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        let mut connection = self.connection().await?;
+        self.timed("EXPIREAT", connection.expire_at(key, unix_secs))
+            .await
+    }
+
+    /// Asynchronously deletes all keys of the currently selected database.
+    ///
+    /// This method sends `FLUSHDB`, which is handy for test teardown but destroys data, so it
+    /// requires an explicitly constructed `FlushConfirm`. Only the database selected by the
+    /// connection (e.g. `/1` in `redis://127.0.0.1:6379/1`) is flushed; other databases are
+    /// never touched, as `FLUSHALL` is never sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `confirm` - The confirmation, created with `FlushConfirm::yes_really`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating whether the database was flushed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::{Client, FlushConfirm};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::from_url("redis://127.0.0.1:6379/15").await?;
+    ///
+    ///     client.flush_db(FlushConfirm::yes_really()).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn flush_db(&self, confirm: FlushConfirm) -> Result<()> {
+        let FlushConfirm(()) = confirm;
+
+        warn!("Flushing the selected Redis database");
+
+        let mut connection = self.connection().await?;
+        self.timed("FLUSHDB", cmd("FLUSHDB").query_async(&mut connection))
+            .await
+    }
+
+    /// Asynchronously retrieves the type of the value stored at a key.
+    ///
+    /// This method sends `TYPE` and parses the reply into a `RedisKeyType`, which is useful for
+    /// generic tooling inspecting arbitrary keys. Types unknown to the enum, such as types added
+    /// by modules, are returned as `RedisKeyType::Other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to inspect.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the type of the value, or `RedisKeyType::None` if the key does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::{Client, RedisKeyType};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     if client.key_type("my_key").await? == RedisKeyType::Hash {
+    ///         println!("my_key holds a hash");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn key_type<K>(&self, key: K) -> Result<RedisKeyType>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        let reply: String = self.timed("TYPE", connection.key_type(key)).await?;
+
+        Ok(RedisKeyType::from(reply.as_str()))
+    }
+
+    /// Asynchronously retrieves the internal encoding of a value stored in Redis.
+    ///
+    /// This method sends `OBJECT ENCODING` to inspect how Redis stores the value, e.g. `embstr`,
+    /// `int` or `raw` for strings, `listpack` or `hashtable` for hashes. It is useful for memory
+    /// tuning.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to inspect.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<String>` with the encoding, or `None` if the key does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let encoding: Option<String> = client.object_encoding("my_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn object_encoding<K>(&self, key: K) -> Result<Option<String>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed(
+            "OBJECT",
+            cmd("OBJECT")
+                .arg("ENCODING")
+                .arg(key)
+                .query_async(&mut connection),
+        )
+        .await
+    }
+
+    /// Asynchronously retrieves the idle time of a key stored in Redis.
+    ///
+    /// This method sends `OBJECT IDLETIME` to get the number of seconds since the key was last
+    /// accessed. Note that Redis doesn't track idle time when an LFU `maxmemory-policy` is used
+    /// and returns an error in that case.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to inspect.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<u64>` with the idle time in seconds, or `None` if the key
+    /// does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let idle: Option<u64> = client.object_idletime("my_key").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn object_idletime<K>(&self, key: K) -> Result<Option<u64>>
+    where
+        K: ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+        self.timed(
+            "OBJECT",
+            cmd("OBJECT")
+                .arg("IDLETIME")
+                .arg(key)
+                .query_async(&mut connection),
+        )
+        .await
+    }
+
+    /// Asynchronously retrieves information and statistics about the Redis server.
+    ///
+    /// This method sends `INFO [section]` and parses the returned `key:value` lines into a map.
+    /// Section headers (`# Server`), blank lines and malformed lines are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - An optional section to retrieve, e.g. `"server"` or `"memory"`. When `None`,
+    ///   the default set of sections is returned.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `HashMap` of the reported fields and their values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let info = client.info(Some("memory")).await?;
+    ///     println!("Used memory: {:?}", info.get("used_memory_human"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn info(&self, section: Option<&str>) -> Result<HashMap<String, String>> {
+        let mut command = cmd("INFO");
+
+        if let Some(section) = section {
+            command.arg(section);
+        }
+
+        let mut connection = self.connection().await?;
+        let raw: String = self
+            .timed("INFO", command.query_async(&mut connection))
+            .await?;
+
+        Ok(parse_info(&raw))
+    }
+
+    /// Asynchronously stores the intersection of sets in a destination key with `SINTERSTORE`.
+    ///
+    /// The intersection is computed on the server and stored under `dest`, replacing any value
+    /// stored there. If the result is empty, `dest` is deleted. It is useful for segmenting
+    /// audiences without transferring the members of the sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - The key under which the resulting set is stored.
+    /// * `keys` - The keys of the sets. Missing keys are treated as empty sets.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of members of the resulting set. An empty slice of
+    /// keys is rejected by Redis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let count = client
+    ///         .sinterstore("segment:active_premium", &["users:active", "users:premium"])
+    ///         .await?;
+    ///     println!("Active premium users: {}", count);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sinterstore(&self, dest: &str, keys: &[&str]) -> Result<usize> {
+        self.set_store("SINTERSTORE", dest, keys).await
+    }
+
+    /// Asynchronously stores the union of sets in a destination key with `SUNIONSTORE`.
+    ///
+    /// The union is computed on the server and stored under `dest`, replacing any value
+    /// stored there. If the result is empty, `dest` is deleted. It is useful for segmenting
+    /// audiences without transferring the members of the sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - The key under which the resulting set is stored.
+    /// * `keys` - The keys of the sets. Missing keys are treated as empty sets.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of members of the resulting set. An empty slice of
+    /// keys is rejected by Redis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let count = client
+    ///         .sunionstore("segment:reachable", &["users:email", "users:push"])
+    ///         .await?;
+    ///     println!("Reachable users: {}", count);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sunionstore(&self, dest: &str, keys: &[&str]) -> Result<usize> {
+        self.set_store("SUNIONSTORE", dest, keys).await
+    }
+
+    /// Asynchronously stores the difference of sets in a destination key with `SDIFFSTORE`.
+    ///
+    /// The difference between the first set and all following sets is computed on the server
+    /// and stored under `dest`, replacing any value stored there. If the result is empty, `dest`
+    /// is deleted. It is useful for segmenting audiences without transferring the members of
+    /// the sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - The key under which the resulting set is stored.
+    /// * `keys` - The keys of the sets. Missing keys are treated as empty sets.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of members of the resulting set. An empty slice of
+    /// keys is rejected by Redis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let count = client
+    ///         .sdiffstore("segment:free", &["users:active", "users:premium"])
+    ///         .await?;
+    ///     println!("Active free users: {}", count);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sdiffstore(&self, dest: &str, keys: &[&str]) -> Result<usize> {
+        self.set_store("SDIFFSTORE", dest, keys).await
+    }
+
+    /// Asynchronously checks whether several values are members of a set with `SMISMEMBER`.
+    ///
+    /// All members are checked in a single round trip instead of one `SISMEMBER` per member.
+    /// Requires Redis 6.2 or later.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the set.
+    /// * `members` - The values to check.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `Vec<bool>` with one entry per member, in the order of `members`.
+    /// A missing set is treated as an empty set, so every entry is `false`. An empty slice of
+    /// members returns an empty vector without contacting Redis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let online = client
+    ///         .smismember("users:online", &["alice", "bob", "carol"])
+    ///         .await?;
+    ///     println!("Online: {:?}", online);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn smismember<K, V>(&self, key: K, members: &[V]) -> Result<Vec<bool>>
+    where
+        K: for<'a> ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        if members.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            "SMISMEMBER",
+            cmd("SMISMEMBER")
+                .arg(key)
+                .arg(members)
+                .query_async(&mut connection),
+        )
+        .await
+    }
+}
+
+// Scan
+impl Client {
+    /// Creates a paginated iterator over keys using the `SCAN` command.
+    ///
+    /// This method acquires a dedicated connection from the pool and wraps it into a
+    /// `PagableRedisScan`, which implements the `Pagable` trait. Each page contains up to
+    /// `per_page` keys; the iterator keeps scanning until a page is full or the cursor completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Optional `MATCH` pattern, e.g. `"user:*"`. `None` scans all keys.
+    /// * `per_page` - The number of keys to return per page.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PagableRedisScan` positioned at the start of the keyspace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::redis::Client;
+    /// use grapple_db::Pagable;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let mut pages = client.scan_pages(Some("session:*"), 100).await?;
+    ///     while let Some(keys) = pages.next_page().await {
+    ///         println!("Page: {:?}", keys);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn scan_pages(
+        &self,
+        pattern: Option<&str>,
+        per_page: usize,
+    ) -> Result<PagableRedisScan> {
+        let connection = self.connection().await?;
+        Ok(PagableRedisScan::new(connection, pattern, per_page))
+    }
+}
+
+// Utility methods
+impl Client {
+    /// Awaits a command future and emits a warning if it exceeds the slow command threshold.
+    async fn timed<T>(
+        &self,
+        command: &str,
+        future: impl Future<Output = RedisResult<T>>,
+    ) -> Result<T> {
+        let Some(threshold) = self.slow_command else {
+            return Ok(future.await?);
+        };
+
+        let started = Instant::now();
+        let res = future.await;
+        let elapsed = started.elapsed();
+
+        if elapsed > threshold {
+            warn!(
+                "Slow command {}: took {:?} (threshold {:?})",
+                command, elapsed, threshold
+            );
+        }
+
+        Ok(res?)
+    }
+
+    /// Sets the pairs with `MSET`, splitting them into chunks of `mset_chunk_size` pairs.
+    ///
+    /// Small batches are sent as a single `MSET`. Larger batches are sent as one `MSET` per
+    /// chunk in a single pipeline: each chunk is applied atomically, and the call succeeds only
+    /// if every chunk succeeds.
+    async fn mset_chunked<K, V>(&self, pairs: &[(K, V)]) -> Result<String>
+    where
+        K: ToRedisArgs + Send + Sync,
+        V: ToRedisArgs + Send + Sync,
+    {
+        let mut connection = self.connection().await?;
+
+        if pairs.len() <= self.mset_chunk_size {
+            return self.timed("MSET", connection.mset(pairs)).await;
+        }
+
+        let mut pipeline = pipe();
+        for chunk in pairs.chunks(self.mset_chunk_size) {
+            pipeline.mset(chunk).ignore();
+        }
+
+        let _: () = self
+            .timed("PIPELINE", pipeline.query_async(&mut connection))
+            .await?;
+
+        Ok("OK".to_string())
+    }
+
+    /// Runs a blocking list pop command, e.g. `BLPOP key timeout`, returning the popped value.
+    ///
+    /// The command runs on a dedicated connection when the client has a `blocking_client`, with
+    /// a response timeout outlasting `timeout`, or no response timeout for a zero `timeout`.
+    async fn blocking_pop<V, K>(
+        &self,
+        command: &str,
+        key: K,
+        timeout: Duration,
+    ) -> Result<Option<V>>
+    where
+        V: FromRedisValue,
+        K: for<'a> ToRedisArgs + Send + Sync,
+    {
+        validate_key(&key)?;
+
+        let mut blocking = cmd(command);
+        blocking.arg(key).arg(timeout.as_secs_f64());
+
+        let reply: Option<(Value, Value)> = match &self.blocking_client {
+            Some(client) => {
+                let response_timeout = if timeout.is_zero() {
+                    Duration::MAX
+                } else {
+                    timeout.saturating_add(BLOCKING_RESPONSE_MARGIN)
+                };
+                let config = AsyncConnectionConfig::new().set_response_timeout(response_timeout);
+
+                let mut connection = client
+                    .get_multiplexed_async_connection_with_config(&config)
+                    .await?;
+                self.timed(command, blocking.query_async(&mut connection))
+                    .await?
+            }
+            None => {
+                let mut connection = self.connection().await?;
+                self.timed(command, blocking.query_async(&mut connection))
+                    .await?
+            }
+        };
+
+        reply.map(|(_, value)| decode(value)).transpose()
+    }
+
+    /// Runs a set algebra command storing its result, e.g. `SINTERSTORE dest key [key ...]`.
+    async fn set_store(&self, command: &str, dest: &str, keys: &[&str]) -> Result<usize> {
+        validate_key(&dest)?;
+
+        for key in keys {
+            validate_key(key)?;
+        }
+
+        let mut connection = self.connection().await?;
+        self.timed(
+            command,
+            cmd(command)
+                .arg(dest)
+                .arg(keys)
+                .query_async(&mut connection),
+        )
+        .await
+    }
+
+    /// Converts a value into command arguments, compressing it if compression is enabled.
+    ///
+    /// Only single-argument values are compressed; others are passed through unchanged.
+    #[cfg(feature = "compression")]
+    fn encode(&self, value: impl ToRedisArgs) -> Result<Vec<Vec<u8>>> {
+        let mut args = value.to_redis_args();
+
+        if let (Some(codec), [arg]) = (self.compression, args.as_mut_slice()) {
+            *arg = compress(codec, arg)?;
+        }
+
+        Ok(args)
+    }
+
+    /// Converts a value into command arguments. Compression requires the `compression` feature.
+    #[cfg(not(feature = "compression"))]
+    fn encode(&self, value: impl ToRedisArgs) -> Result<Vec<Vec<u8>>> {
+        Ok(value.to_redis_args())
+    }
+}
+
+/// Decompresses the compressed values of a response and converts it into the requested type.
+#[cfg(feature = "compression")]
+fn decode<V: FromRedisValue>(value: Value) -> Result<V> {
+    fn decompress_value(value: Value) -> Result<Value> {
+        Ok(match value {
+            Value::BulkString(bytes) => Value::BulkString(decompress(bytes)?),
+            Value::Array(values) => Value::Array(
+                values
+                    .into_iter()
+                    .map(decompress_value)
+                    .collect::<Result<_>>()?,
+            ),
+            value => value,
+        })
+    }
+
+    Ok(V::from_redis_value(&decompress_value(value)?)?)
+}
+
+/// Converts a response into the requested type. Decompression requires the `compression` feature.
+#[cfg(not(feature = "compression"))]
+fn decode<V: FromRedisValue>(value: Value) -> Result<V> {
+    Ok(V::from_redis_value(&value)?)
+}
+
+/// Parses the output of the `INFO` command into a map.
+///
+/// Section headers, blank lines and lines without a `key:value` pair are skipped.
+fn parse_info(raw: &str) -> HashMap<String, String> {
+    raw.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Prepends the model prefix to the keys of the pairs.
+fn prefixed_pairs<'a, M: RedisModel>(
+    pairs: &[(&M::Key, &'a M::Value)],
+) -> Vec<(Vec<u8>, &'a M::Value)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (prefixed_key(M::PREFIX, *key), *value))
+        .collect()
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Result<T> = super::Result<T>; // For tests.
+
+    use std::time::Duration;
+
+    use crate::redis;
+    use crate::redis::macros::FromRedisValue;
+    use crate::redis::RedisModel;
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    use super::*;
+
+    // region:    --- Tst Struct
+
+    #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
+    struct Tst {
+        key: String,
+        a: u64,
+        b: u64,
+    }
+
+    impl RedisModel for Tst {
+        type Key = String;
+        type Value = String;
+
+        fn key_ref(&self) -> &Self::Key {
+            &self.key
+        }
+
+        fn key(&self) -> redis::Result<Self::Key> {
+            Ok(self.key.clone())
+        }
+
+        fn value(&self) -> redis::Result<impl deadpool_redis::redis::ToRedisArgs + Send + Sync> {
+            Ok(serde_json::to_string(&self)?)
+        }
+
+        fn value_ref(&self) -> &Self::Value {
+            // Для тестов нам не нужно реальное значение
+            static PLACEHOLDER: String = String::new();
+            &PLACEHOLDER
+        }
+    }
+
+    impl Tst {
+        pub fn inc(mut self, value: u64) -> Self {
+            self.a += value;
+            self.b += value;
+            self
+        }
+
+        pub fn default(key: impl AsRef<str>) -> Self {
+            Self {
+                key: key.as_ref().to_string(),
+                a: 3,
+                b: 4,
+            }
+        }
+    }
+
+    // endregion: --- Tst Struct
+
+    async fn get_client() -> Client {
+        Client::default().await.unwrap()
+    }
+
+    // region:    --- GET TESTS
+
+    #[tokio::test]
+    async fn test_redis_get() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_get";
+
+        // Create model
+        let fx_model = Tst::default(key);
+        client.set(&fx_model).await?;
+
+        // Test
+        assert_eq!(Some(fx_model), client.get(key).await?);
+
+        // Clear
+        client.del(key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_or() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_get_or_{}", Uuid::new_v4());
+
+        // Test: missing key returns the default
+        assert_eq!(20, client.get_or(&key, 20).await?);
+
+        // Test: stored value is returned
+        client.set(&(key.clone(), 50)).await?;
+        assert_eq!(50, client.get_or(&key, 20).await?);
+
+        // Test: conversion errors are propagated
+        client.set(&(key.clone(), "not a number")).await?;
+        assert!(client.get_or(&key, 20).await.is_err());
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mget() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = "test_redis_mget1".to_string();
+        let key2 = "test_redis_mget2".to_string();
+
+        // Create models
+        let model1 = Tst::default(&key1);
+        let model2 = Tst::default(&key2);
+
+        // Используем кортежи для mset
+        let tuple1 = (key1.clone(), serde_json::to_string(&model1)?);
+        let tuple2 = (key2.clone(), serde_json::to_string(&model2)?);
+        client.mset([&tuple1, &tuple2]).await?;
+
+        // Test mget
+        let got: Vec<Option<Tst>> = client.mget(&[&key1, &key2]).await?;
+        assert_eq!(vec![Some(model1), Some(model2)], got);
+
+        // Clear
+        client.mdel([&key1, &key2]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mget_concurrent() -> Result<()> {
+        let client = get_client().await;
+
+        let id = Uuid::new_v4();
+        let keys: Vec<String> = (0..20)
+            .map(|i| format!("test_redis_mget_concurrent{i}_{id}"))
+            .collect();
+
+        // Create values, leaving every third key missing
+        let tuples: Vec<(String, String)> = keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 3 != 0)
+            .map(|(_, key)| {
+                (
+                    key.clone(),
+                    serde_json::to_string(&Tst::default(key)).unwrap(),
+                )
+            })
+            .collect();
+        client.mset(tuples.iter().collect::<Vec<_>>()).await?;
+
+        // Test
+        let got: Vec<Option<Tst>> = client.mget_concurrent(&keys, 4).await?;
+
+        let expected: Vec<Option<Tst>> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (i % 3 != 0).then(|| Tst::default(key)))
+            .collect();
+        assert_eq!(expected, got);
+
+        let got: Vec<Option<Tst>> = client.mget_concurrent(Vec::<String>::new(), 4).await?;
+        assert!(got.is_empty());
+
+        // Clear
+        client.mdel(&keys).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_wrong_type() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_get_wrong_type_{}", Uuid::new_v4());
+
+        // Create hash
+        let mut connection = client.connection().await?;
+        let _: () = connection.hset(&key, "field", "value").await?;
+
+        // Test
+        let err = client.get::<String, _>(&key).await.unwrap_err();
+        assert!(err.is_type_error());
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mget_map() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = format!("test_redis_mget_map1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_mget_map2_{}", Uuid::new_v4());
+        let missing = format!("test_redis_mget_map_missing_{}", Uuid::new_v4());
+
+        // Create models
+        let model1 = Tst::default(&key1);
+        let model2 = Tst::default(&key2).inc(1);
+
+        client.set(&model1).await?;
+        client.set(&model2).await?;
+
+        // Test
+        let values: HashMap<String, Tst> = client
+            .mget_map(&[key1.as_str(), missing.as_str(), key2.as_str()])
+            .await?;
+        assert_eq!(2, values.len());
+        assert_eq!(Some(&model1), values.get(&key1));
+        assert_eq!(Some(&model2), values.get(&key2));
+        assert!(!values.contains_key(&missing));
+
+        // Duplicate keys collapse into one entry
+        let values: HashMap<String, Tst> = client.mget_map(&[&key1, &key1]).await?;
+        assert_eq!(1, values.len());
+
+        // Clear
+        client.mdel([&key1, &key2]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mget_pairs() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = format!("test_redis_mget_pairs1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_mget_pairs2_{}", Uuid::new_v4());
+        let key3 = format!("test_redis_mget_pairs3_{}", Uuid::new_v4());
+
+        // Create models
+        let model1 = Tst::default(&key1);
+        let model3 = Tst::default(&key3);
+        client.set(&model1).await?;
+        client.set(&model3).await?;
+
+        // Test
+        let got: Vec<(String, Option<Tst>)> = client
+            .mget_pairs(&[key1.clone(), key2.clone(), key3.clone()])
+            .await?;
+        assert_eq!(
+            vec![
+                (key1.clone(), Some(model1)),
+                (key2, None),
+                (key3.clone(), Some(model3))
+            ],
+            got
+        );
+
+        // Clear
+        client.mdel([&key1, &key3]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_pipe_get() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = format!("test_redis_pipe_get1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_pipe_get2_{}", Uuid::new_v4());
+
+        client.set(&(key1.clone(), "first".to_string())).await?;
+
+        // Test
+        let got: Vec<Option<String>> = client.pipe_get(&[&key1, &key2]).await?;
+        assert_eq!(vec![Some("first".to_string()), None], got);
+
+        // Clear
+        client.del(&key1).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get2() -> Result<()> {
+        let client = get_client().await;
+
+        let key_str = format!("test_redis_get2_str_{}", Uuid::new_v4());
+        let key_int = format!("test_redis_get2_int_{}", Uuid::new_v4());
+
+        client.set(&(key_str.clone(), "value".to_string())).await?;
+        client.set(&(key_int.clone(), 42i64)).await?;
+
+        // Test
+        let got: (Option<String>, Option<i64>) = client.get2(&key_str, &key_int).await?;
+        assert_eq!((Some("value".to_string()), Some(42)), got);
+
+        // Clear
+        client.mdel([&key_str, &key_int]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_with_ttl() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_get_with_ttl_{}", Uuid::new_v4());
+
+        // Create model
+        let fx_model = Tst::default(&key);
+        client.set_ex(&fx_model, 60).await?;
+
+        // Test
+        let (got, ttl) = client.get_with_ttl::<Tst, _>(&key).await?.unwrap();
+        assert_eq!(fx_model, got);
+        assert!(ttl > 0 && ttl <= 60);
+
+        client.set(&fx_model).await?;
+        let (_, ttl) = client.get_with_ttl::<Tst, _>(&key).await?.unwrap();
+        assert_eq!(-1, ttl);
+
+        // Clear
+        client.del(&key).await?;
+
+        assert!(client.get_with_ttl::<Tst, _>(&key).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_ex() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_get_ex";
+
+        // Create model
+        let fx_model = Tst::default(key);
+        client.set(&fx_model).await?;
+
+        // Test
+        assert_eq!(Some(fx_model), client.get_ex(key, Expiry::EX(2)).await?);
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+
+        assert_eq!(None::<Tst>, client.get(key).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_del() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_get_del";
+
+        // Create model
+        let fx_model = Tst::default(key);
+        client.set(&fx_model).await?;
+
+        // Test
+        assert_eq!(Some(fx_model), client.get_del(key).await?);
+
+        assert_eq!(None::<Tst>, client.get(key).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_getset() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_getset";
+
+        // Create model
+        let to_get = Tst::default(key);
+        let to_set = Tst::default(key).inc(5);
+
+        client.set(&to_get).await?;
+
+        // Test
+        assert_eq!(Some(to_get), client.getset(&to_set).await?);
+        assert_eq!(Some(to_set), client.get(key).await?);
+
+        // Clear
+        client.del(key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_get() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_get_{}", Uuid::new_v4());
+
+        // Create model
+        let to_get = Tst::default(&key);
+        let to_set = Tst::default(&key).inc(5);
+
+        // Test
+        assert_eq!(None::<Tst>, client.set_get(&to_get).await?);
+        assert_eq!(Some(to_get), client.set_get(&to_set).await?);
+        assert_eq!(Some(to_set), client.get(&key).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_blocking_pop() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_blocking_pop_{}", Uuid::new_v4());
+
+        // Test: producer pushes after a short delay
+        let producer = {
+            let client = client.clone();
+            let key = key.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+
+                let mut connection = client.connection().await?;
+                let _: () = connection.rpush(&key, &[1, 2, 3]).await?;
+
+                Ok::<_, Error>(())
+            })
+        };
+
+        let value: Option<i32> = client.blpop(&key, Duration::from_secs(5)).await?;
+        assert_eq!(Some(1), value);
+        producer.await.unwrap()?;
+
+        let value: Option<i32> = client.brpop(&key, Duration::from_secs(5)).await?;
+        assert_eq!(Some(3), value);
+
+        // Test: timeout with no item
+        client.del(&key).await?;
+        let value: Option<i32> = client.blpop(&key, Duration::from_millis(100)).await?;
+        assert_eq!(None, value);
+        let value: Option<i32> = client.brpop(&key, Duration::from_millis(100)).await?;
+        assert_eq!(None, value);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_blocking_pop_long_wait() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_blocking_pop_long_wait_{}", Uuid::new_v4());
+
+        // Test: producer pushes after more than a second
+        let producer = {
+            let client = client.clone();
+            let key = key.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(1500)).await;
+
+                let mut connection = client.connection().await?;
+                let _: () = connection.rpush(&key, &[1, 2]).await?;
+
+                Ok::<_, Error>(())
+            })
+        };
+
+        let value: Option<i32> = client.blpop(&key, Duration::from_secs(5)).await?;
+        assert_eq!(Some(1), value);
+        producer.await.unwrap()?;
+
+        let value: Option<i32> = client.brpop(&key, Duration::from_secs(5)).await?;
+        assert_eq!(Some(2), value);
+
+        // Test: timeout of more than a second elapses with no item
+        let started = Instant::now();
+        let value: Option<i32> = client.blpop(&key, Duration::from_millis(1500)).await?;
+        assert_eq!(None, value);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+
+        let started = Instant::now();
+        let value: Option<i32> = client.brpop(&key, Duration::from_millis(1500)).await?;
+        assert_eq!(None, value);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_rotate() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_rotate_{}", Uuid::new_v4());
+
+        // Create model
+        let old = Tst::default(&key);
+        let new = Tst::default(&key).inc(5);
+
+        // Test
+        assert_eq!(None::<Tst>, client.rotate(&old).await?);
+        assert_eq!(Some(old.clone()), client.get(&key).await?);
+
+        assert_eq!(Some(old), client.rotate(&new).await?);
+        assert_eq!(Some(new), client.get(&key).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_get_wrong_type() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_get_wrong_type_{}", Uuid::new_v4());
+
+        // Create hash
+        let mut connection = client.connection().await?;
+        let _: () = connection.hset(&key, "field", "value").await?;
+
+        // Test
+        let err = client
+            .set_get::<_, String>(&Tst::default(&key))
+            .await
+            .unwrap_err();
+        assert!(err.is_type_error());
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    // endregion: --- GET TESTS
+
+    // region:    --- SET TESTS
+
+    #[tokio::test]
+    async fn test_redis_set() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_set";
+
+        // Create model
+        let fx_model = Tst::default(key);
+
+        // Test
+        assert_eq!("OK", client.set(&fx_model).await?);
+        assert_eq!(Some(fx_model), client.get(key).await?);
+
+        // Clear
+        client.del(key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_redis_model_macro() -> Result<()> {
+        #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
+        struct Session {
+            id: String,
+            user: String,
+        }
+
+        crate::redis_model!(Session, key = id: String);
+
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_redis_model_macro_{}", Uuid::new_v4());
+
+        // Create model
+        let fx_model = Session {
+            id: key.clone(),
+            user: "john".to_string(),
+        };
+
+        // Test
+        assert_eq!(&key, fx_model.key_ref());
+        assert_eq!("OK", client.set(&fx_model).await?);
+        assert_eq!(Some(fx_model), client.get(&key).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_model_ttl() -> Result<()> {
+        #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
+        struct TtlTst {
+            key: String,
+        }
+
+        impl RedisModel for TtlTst {
+            type Key = String;
+            type Value = String;
+
+            fn key_ref(&self) -> &Self::Key {
+                &self.key
+            }
+
+            fn key(&self) -> redis::Result<Self::Key> {
+                Ok(self.key.clone())
+            }
+
+            fn value_ref(&self) -> &Self::Value {
+                static PLACEHOLDER: String = String::new();
+                &PLACEHOLDER
+            }
+
+            fn ttl_secs(&self) -> Option<u64> {
+                Some(2)
+            }
+        }
+
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_model_ttl_{}", Uuid::new_v4());
+
+        // Create model
+        let fx_model = TtlTst { key: key.clone() };
+
+        // Test
+        assert_eq!("OK", client.set(&fx_model).await?);
+
+        assert_eq!(Some(fx_model), client.get(&key).await?);
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert_eq!(None::<TtlTst>, client.get(&key).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_tuple_ttl() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_tuple_ttl_{}", Uuid::new_v4());
+
+        // Test
+        assert_eq!(
+            "OK",
+            client.set(&(key.clone(), "v".to_string(), 2u64)).await?
+        );
+
+        assert_eq!(Some("v".to_string()), client.get(&key).await?);
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert_eq!(None::<String>, client.get(&key).await?);
+
+        // Zero TTL is rejected
+        let err = client
+            .set(&(key.clone(), "v".to_string(), 0u64))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidTtl(0)));
+        assert!(!client.exists(&key).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_get_model_migrate() -> Result<()> {
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+        struct MigrateTst {
+            key: String,
+            full_name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct MigrateTstV1 {
+            key: String,
+            name: String,
+        }
+
+        impl RedisModel for MigrateTst {
+            type Key = String;
+            type Value = String;
+
+            fn key_ref(&self) -> &Self::Key {
+                &self.key
+            }
+
+            fn key(&self) -> redis::Result<Self::Key> {
+                Ok(self.key.clone())
+            }
+
+            fn value_ref(&self) -> &Self::Value {
+                static PLACEHOLDER: String = String::new();
+                &PLACEHOLDER
+            }
+
+            fn migrate(raw: &[u8]) -> redis::Result<Self> {
+                if let Ok(model) = serde_json::from_slice(raw) {
+                    return Ok(model);
+                }
+
+                let old: MigrateTstV1 = serde_json::from_slice(raw)?;
+                Ok(Self {
+                    key: old.key,
+                    full_name: old.name,
+                })
+            }
+        }
+
+        let client = get_client().await;
+
+        let key = format!("test_redis_get_model_migrate_{}", Uuid::new_v4());
+        let mut connection = client.connection().await?;
+
+        // Test: missing key
+        assert_eq!(None, client.get_model::<MigrateTst, _>(&key).await?);
+
+        // Test: current format
+        let fx_model = MigrateTst {
+            key: key.clone(),
+            full_name: "John Doe".to_string(),
+        };
+        client.set(&fx_model).await?;
+        assert_eq!(Some(fx_model.clone()), client.get_model(&key).await?);
+
+        // Test: older format is upgraded
+        let old = format!(r#"{{"key":"{key}","name":"John Doe"}}"#);
+        let _: () = connection.set(&key, old).await?;
+        assert_eq!(Some(fx_model), client.get_model(&key).await?);
+
+        // Test: corrupt data is still an error
+        let _: () = connection.set(&key, "not a model").await?;
+        let err = client.get_model::<MigrateTst, _>(&key).await.unwrap_err();
+        assert!(matches!(err, Error::Serde(_)));
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_model_prefix() -> Result<()> {
+        #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
+        struct PrefixTst {
+            key: String,
+        }
+
+        impl RedisModel for PrefixTst {
+            type Key = String;
+            type Value = String;
+
+            const PREFIX: &'static str = "test_prefix:";
+
+            fn key_ref(&self) -> &Self::Key {
+                &self.key
+            }
+
+            fn key(&self) -> redis::Result<Self::Key> {
+                Ok(self.key.clone())
+            }
+
+            fn value_ref(&self) -> &Self::Value {
+                static PLACEHOLDER: String = String::new();
+                &PLACEHOLDER
+            }
+        }
+
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_model_prefix_{}", Uuid::new_v4());
+        let prefixed = format!("test_prefix:{key}");
+
+        // Create model
+        let fx_model = PrefixTst { key: key.clone() };
+
+        // Test
+        assert_eq!(prefixed.as_bytes(), fx_model.prefixed_key()?);
+        assert_eq!("OK", client.set(&fx_model).await?);
+
+        assert_eq!(Some(fx_model), client.get(&prefixed).await?);
+        assert!(!client.exists(&key).await?);
+
+        // Clear
+        client.del(&prefixed).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_invalid_key() -> Result<()> {
+        let client = get_client().await;
+
+        // Test
+        let err = client.set(&Tst::default("")).await.unwrap_err();
+        assert!(matches!(err, redis::Error::InvalidKey(_)));
+
+        let err = client.set(&Tst::default("bad key\n")).await.unwrap_err();
+        assert!(matches!(err, redis::Error::InvalidKey(_)));
+
+        assert!(client.get::<Tst, _>("").await.is_err());
+
+        // Namespaced keys stay valid
+        let key = format!("test:redis:set_invalid_key:{}", Uuid::new_v4());
+        assert_eq!("OK", client.set(&Tst::default(&key)).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mset() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = "test_redis_mset1".to_string();
+        let key2 = "test_redis_mset2".to_string();
+
+        // Create models
+        let model1 = Tst::default(&key1);
+        let model2 = Tst::default(&key2);
+
+        // Используем кортежи для mset
+        let tuple1 = (key1.clone(), serde_json::to_string(&model1)?);
+        let tuple2 = (key2.clone(), serde_json::to_string(&model2)?);
+
+        assert_eq!("OK", client.mset([&tuple1, &tuple2]).await?);
+
+        assert_eq!(Some(model1), client.get(&key1).await?);
+        assert_eq!(Some(model2), client.get(&key2).await?);
+
+        // Clear
+        client.mdel([&key1, &key2]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mset_chunked() -> Result<()> {
+        let client = get_client().await;
+
+        let prefix = format!("test_redis_mset_chunked_{}", Uuid::new_v4());
+
+        // Create pairs spanning several chunks
+        let pairs: Vec<(String, String)> = (0..1500)
+            .map(|i| (format!("{prefix}_{i}"), i.to_string()))
+            .collect();
+        let keys: Vec<String> = pairs.iter().map(|(key, _)| key.clone()).collect();
+
+        // Test
+        assert_eq!("OK", client.mset(pairs.as_slice()).await?);
+
+        let values: Vec<Option<String>> = client.mget(&keys).await?;
+        assert_eq!(1500, values.len());
+        for (i, value) in values.into_iter().enumerate() {
+            assert_eq!(Some(i.to_string()), value);
+        }
+
+        // Clear
+        assert_eq!(1500, client.mdel(&keys).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mset_checked() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = format!("test_redis_mset_checked1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_mset_checked2_{}", Uuid::new_v4());
+
+        let tuple1 = (key1.clone(), "value1".to_string());
+        let tuple2 = (key2.clone(), "value2".to_string());
+        let duplicate = (key1.clone(), "value3".to_string());
+
+        // Test
+        let err = client
+            .mset_checked([&tuple1, &tuple2, &duplicate])
+            .await
+            .unwrap_err();
+        assert!(matches!(&err, Error::DuplicateKeys(keys) if keys == &vec![key1.clone()]));
+        assert!(!client.exists(&key1).await?);
+
+        assert_eq!("OK", client.mset_checked([&tuple1, &tuple2]).await?);
+        assert_eq!(Some("value1".to_string()), client.get(&key1).await?);
+
+        // Clear
+        client.mdel([&key1, &key2]).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mset_owned_and_iter() -> Result<()> {
+        use crate::redis::collector::collect_iter;
+
+        let client = get_client().await;
+
+        let key1 = format!("test_redis_mset_owned_and_iter1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_mset_owned_and_iter2_{}", Uuid::new_v4());
+        let key3 = format!("test_redis_mset_owned_and_iter3_{}", Uuid::new_v4());
+
+        // Test: owned vector of models
+        let owned = vec![
+            (key1.clone(), "value1".to_string()),
+            (key2.clone(), "value2".to_string()),
+        ];
+        assert_eq!("OK", client.mset(owned).await?);
+
+        assert_eq!(Some("value1".to_string()), client.get(&key1).await?);
+        assert_eq!(Some("value2".to_string()), client.get(&key2).await?);
+
+        // Test: iterator over models
+        let models = [
+            (key1.clone(), "skipped".to_string()),
+            (key3.clone(), "value3".to_string()),
+        ];
+        let iter = models.iter().filter(|(key, _)| key == &key3);
+        assert_eq!("OK", client.mset(collect_iter(iter)).await?);
+
+        assert_eq!(Some("value1".to_string()), client.get(&key1).await?);
+        assert_eq!(Some("value3".to_string()), client.get(&key3).await?);
+
+        // Clear
+        client.mdel([&key1, &key2, &key3]).await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn test_redis_set_compressed() -> Result<()> {
+        let id = Uuid::new_v4();
+        let plain = get_client().await;
+
+        for codec in [Codec::Gzip, Codec::Zstd] {
+            let client = get_client().await.with_compression(codec);
+            let key = format!("test_redis_set_compressed_{codec:?}_{id}");
+
+            // Create a large model
+            let mut model = Tst::default(&key);
+            model.key = model.key.repeat(100);
+            let json = serde_json::to_string(&model)?;
+
+            // Test: stored bytes are smaller than the JSON
+            client.set(&(key.clone(), json.clone())).await?;
+
+            let stored = client.get_bytes(&key).await?.unwrap();
+            assert!(stored.len() < json.len());
+
+            // Test: reads decompress transparently, also without compression configured
+            assert_eq!(Some(model.clone()), client.get::<Tst, _>(&key).await?);
+            assert_eq!(Some(model.clone()), plain.get::<Tst, _>(&key).await?);
+            assert_eq!(
+                vec![Some(model.clone()), None],
+                plain
+                    .mget::<_, _, Tst>(vec![key.clone(), format!("{key}_missing")])
+                    .await?
+            );
+
+            // Test: mixed reads of compressed and plain values
+            let plain_key = format!("{key}_plain");
+            plain.set(&(plain_key.clone(), json.clone())).await?;
+            assert_eq!(Some(model), client.get::<Tst, _>(&plain_key).await?);
+
+            // Clear
+            client.mdel([&key, &plain_key]).await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn test_redis_compressed_read_paths() -> Result<()> {
+        let id = Uuid::new_v4();
+
+        for codec in [Codec::Gzip, Codec::Zstd] {
+            let client = get_client().await.with_compression(codec);
+            let key1 = format!("test_redis_compressed_read_paths1_{codec:?}_{id}");
+            let key2 = format!("test_redis_compressed_read_paths2_{codec:?}_{id}");
+            let missing = format!("test_redis_compressed_read_paths_missing_{codec:?}_{id}");
+
+            let mut model1 = Tst::default(&key1);
+            model1.key = model1.key.repeat(100);
+            let mut model2 = Tst::default(&key2).inc(1);
+            model2.key = model2.key.repeat(100);
+
+            // Create compressed values
+            client
+                .set(&(key1.clone(), serde_json::to_string(&model1)?))
+                .await?;
+            assert!(
+                client
+                    .set_nx(&(key2.clone(), serde_json::to_string(&model2)?))
+                    .await?
+            );
+            assert!(client
+                .get_bytes(&key2)
+                .await?
+                .unwrap()
+                .starts_with(b"\0GDBZ"));
+
+            // Test: pipe_get
+            assert_eq!(
+                vec![Some(model1.clone()), Some(model2.clone()), None],
+                client
+                    .pipe_get::<Option<Tst>, _>(&[&key1, &key2, &missing])
+                    .await?
+            );
+
+            // Test: get2
+            assert_eq!(
+                (Some(model1.clone()), Some(model2.clone())),
+                client.get2::<Tst, Tst, _, _>(&key1, &key2).await?
+            );
+
+            // Test: get_with_ttl
+            assert_eq!(
+                Some((model1.clone(), -1)),
+                client.get_with_ttl::<Tst, _>(&key1).await?
+            );
+            assert_eq!(None, client.get_with_ttl::<Tst, _>(&missing).await?);
+
+            // Test: set_nx round trip
+            assert_eq!(Some(model2), client.get::<Tst, _>(&key2).await?);
+
+            // Clear
+            client.mdel([&key1, &key2]).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_many() -> Result<()> {
+        #[derive(Debug, Clone, Serialize, Deserialize, FromRedisValue, PartialEq)]
+        struct TtlTst {
+            key: String,
+            ttl: Option<u64>,
+        }
+
+        impl RedisModel for TtlTst {
+            type Key = String;
+            type Value = String;
+
+            fn key_ref(&self) -> &Self::Key {
+                &self.key
+            }
+
+            fn key(&self) -> redis::Result<Self::Key> {
+                Ok(self.key.clone())
+            }
+
+            fn value_ref(&self) -> &Self::Value {
+                static PLACEHOLDER: String = String::new();
+                &PLACEHOLDER
+            }
+
+            fn ttl_secs(&self) -> Option<u64> {
+                self.ttl
+            }
+        }
+
+        let client = get_client().await;
+
+        let id = Uuid::new_v4();
+        let keys = [0, 1, 2].map(|i| format!("test_redis_set_many_{id}_{i}"));
+
+        // Create models
+        let model1 = TtlTst {
+            key: keys[0].clone(),
+            ttl: None,
+        };
+        let model2 = TtlTst {
+            key: keys[1].clone(),
+            ttl: Some(2),
+        };
+        let model3 = TtlTst {
+            key: keys[2].clone(),
+            ttl: None,
+        };
+
+        // Test
+        client.set_many(&[&model1, &model2, &model3]).await?;
+
+        assert_eq!(Some(model1.clone()), client.get(&keys[0]).await?);
+        assert_eq!(Some(model2), client.get(&keys[1]).await?);
+        assert_eq!(Some(model3.clone()), client.get(&keys[2]).await?);
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+
+        assert_eq!(Some(model1), client.get(&keys[0]).await?);
+        assert_eq!(None::<TtlTst>, client.get(&keys[1]).await?);
+        assert_eq!(Some(model3), client.get(&keys[2]).await?);
+
+        // Test invalid TTL
+        let invalid = TtlTst {
+            key: format!("test_redis_set_many_{id}_invalid"),
+            ttl: Some(0),
+        };
+        let res = client.set_many(&[&invalid]).await;
+        assert!(matches!(res, Err(Error::InvalidTtl(0))));
+
+        // Clear
+        client.mdel(&keys).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_bytes() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_set_bytes_{}", Uuid::new_v4());
+
+        // Test non-UTF-8 bytes
+        let bytes = [0xff, 0x00, 0xfe, 0x80, 0xc3];
+        assert!(std::str::from_utf8(&bytes).is_err());
+
+        client.set_bytes(&key, &bytes).await?;
+        assert_eq!(Some(bytes.to_vec()), client.get_bytes(&key).await?);
+
+        // Test JSON-stored value
+        let fx_model = Tst::default(&key);
+        client.set(&fx_model).await?;
+        assert_eq!(
+            Some(serde_json::to_vec(&fx_model)?),
+            client.get_bytes(&key).await?
+        );
+
+        // Clear
+        client.del(&key).await?;
+
+        assert!(client.get_bytes(&key).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_ex() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_set_ex";
+
+        // Create model
+        let fx_model = Tst::default(key);
+
+        // Test
+        assert_eq!("OK", client.set_ex(&fx_model, 2).await?);
+
+        assert_eq!(Some(fx_model), client.get(key).await?);
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert_eq!(None::<Tst>, client.get(key).await?);
+
+        // Clear
+        client.del(key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_ex_invalid_ttl() -> Result<()> {
+        // Nothing listens on this port, so reaching the server would fail differently
+        let client = Client::from_url("redis://127.0.0.1:1").await?;
+
+        let fx_model = Tst::default("test_redis_set_ex_invalid_ttl");
+
+        // Test
+        for secs in [0, i64::MAX as u64 / 1000, u64::MAX] {
+            let err = client.set_ex(&fx_model, secs).await.unwrap_err();
+            assert!(matches!(err, Error::InvalidTtl(ttl) if ttl == secs));
         }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_set_nx() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_set_nx1".to_string();
+
+        // Create models
+        let model1 = Tst::default(&key);
+        let model2 = Tst::default(&key).inc(5);
+
+        // Используем кортежи для set_nx
+        let _tuple1 = (key.clone(), serde_json::to_string(&model1)?);
+        let _tuple2 = (key.clone(), serde_json::to_string(&model2)?);
+
+        // Test
+        assert!(client.set_nx(&model1).await?);
+        assert_eq!(Some(model1.clone()), client.get(&key).await?);
+
+        assert!(!client.set_nx(&model2).await?);
+        assert_eq!(Some(model1), client.get(&key).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
     }
 
-    impl Tst {
-        pub fn inc(mut self, value: u64) -> Self {
-            self.a += value;
-            self.b += value;
-            self
-        }
+    #[tokio::test]
+    async fn test_redis_mset_nx() -> Result<()> {
+        let client = get_client().await;
 
-        pub fn default(key: impl AsRef<str>) -> Self {
-            Self {
-                key: key.as_ref().to_string(),
-                a: 3,
-                b: 4,
-            }
-        }
+        let key1 = format!("test_redis_mset_nx1_{}", Uuid::new_v4());
+        let key2 = format!("test_redis_mset_nx2_{}", Uuid::new_v4());
+
+        // Create models
+        let model1_before = Tst::default(&key1);
+        let model2_before = Tst::default(&key2);
+        let model1_after = Tst::default(&key1).inc(3);
+        let model2_after = Tst::default(&key2).inc(3);
+
+        // Используем кортежи для mset_nx
+        let tuple1_before = (key1.clone(), serde_json::to_string(&model1_before)?);
+        let tuple2_before = (key2.clone(), serde_json::to_string(&model2_before)?);
+        let tuple1_after = (key1.clone(), serde_json::to_string(&model1_after)?);
+        let tuple2_after = (key2.clone(), serde_json::to_string(&model2_after)?);
+
+        // Test
+        assert!(client.mset_nx([&tuple1_before, &tuple2_before]).await?);
+        assert_eq!(
+            vec![Some(model1_before.clone()), Some(model2_before.clone())],
+            client.mget(&[&key1, &key2]).await?
+        );
+
+        assert!(!client.mset_nx([&tuple1_after, &tuple2_after]).await?);
+        assert_eq!(
+            vec![Some(model1_before), Some(model2_before)],
+            client.mget(&[&key1, &key2]).await?
+        );
+
+        // Clear
+        client.mdel([&key1, &key2]).await?;
+
+        Ok(())
     }
 
-    // endregion: --- Tst Struct
+    #[tokio::test]
+    async fn test_redis_mset_nx_conflicts() -> Result<()> {
+        let client = get_client().await;
 
-    async fn get_client() -> Client {
-        Client::default().await.unwrap()
+        let id = Uuid::new_v4();
+        let keys = [1, 2, 3].map(|i| format!("test_redis_mset_nx_conflicts{i}_{id}"));
+
+        // Create values
+        let tuples = keys.clone().map(|key| {
+            (
+                key.clone(),
+                serde_json::to_string(&Tst::default(&key)).unwrap(),
+            )
+        });
+        client.set(&tuples[1]).await?;
+
+        // Test
+        let conflicts = client
+            .mset_nx_conflicts([&tuples[0], &tuples[1], &tuples[2]])
+            .await?;
+        assert_eq!(vec![keys[1].clone()], conflicts);
+        assert!(!client.exists(&keys[0]).await?);
+        assert!(!client.exists(&keys[2]).await?);
+
+        client.del(&keys[1]).await?;
+
+        let conflicts = client
+            .mset_nx_conflicts([&tuples[0], &tuples[1], &tuples[2]])
+            .await?;
+        assert!(conflicts.is_empty());
+
+        // Clear
+        client.mdel(&keys).await?;
+
+        Ok(())
     }
 
-    // region:    --- GET TESTS
+    #[cfg(feature = "redis-json")]
+    #[tokio::test]
+    #[ignore = "requires the RedisJSON module"]
+    async fn test_redis_json_arr_append() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_json_arr_append_{}", Uuid::new_v4());
+
+        // Create document
+        let mut connection = client.connection().await?;
+        let _: () = cmd("JSON.SET")
+            .arg(&key)
+            .arg("$")
+            .arg(r#"{"events":[]}"#)
+            .query_async(&mut connection)
+            .await?;
+
+        // Test
+        assert_eq!(1, client.json_arr_append(&key, "$.events", &"first").await?);
+        assert_eq!(
+            2,
+            client.json_arr_append(&key, "$.events", &"second").await?
+        );
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
 
     #[tokio::test]
-    async fn test_redis_get() -> Result<()> {
+    async fn test_redis_push_capped() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_get";
+        let key = format!("test_redis_push_capped_{}", Uuid::new_v4());
 
-        // Create model
-        let fx_model = Tst::default(key);
-        client.set(&fx_model).await?;
+        // Test
+        for i in 1..=5 {
+            client.push_capped(&key, i, 3).await?;
+        }
+
+        let mut connection = client.connection().await?;
+        let len: usize = connection.llen(&key).await?;
+        assert_eq!(3, len);
+
+        let items: Vec<i32> = connection.lrange(&key, 0, -1).await?;
+        assert_eq!(vec![5, 4, 3], items);
+
+        // Zero length leaves the list empty
+        client.push_capped(&key, 6, 0).await?;
+        let len: usize = connection.llen(&key).await?;
+        assert_eq!(0, len);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_decr_floor() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_decr_floor_{}", Uuid::new_v4());
+
+        // Create counter
+        client.set(&(key.clone(), 3)).await?;
 
         // Test
-        assert_eq!(Some(fx_model), client.get(key).await?);
+        assert_eq!(1, client.decr_floor(&key, 2, 0).await?);
+        assert_eq!(0, client.decr_floor(&key, 2, 0).await?);
+        assert_eq!(0, client.decr_floor(&key, 2, 0).await?);
+        assert_eq!(Some(0), client.get::<i64, _>(&key).await?);
+
+        // Missing key is treated as 0
+        client.del(&key).await?;
+        assert_eq!(-1, client.decr_floor(&key, 5, -1).await?);
+
+        // Values beyond the precision of Lua numbers stay exact
+        let big = (1_i64 << 53) + 4;
+        client.set(&(key.clone(), big)).await?;
+        assert_eq!(big - 1, client.decr_floor(&key, 1, 0).await?);
+        assert_eq!(Some(big - 1), client.get::<i64, _>(&key).await?);
+
+        // Non-integer values are rejected and left unchanged
+        for value in ["1.5", "abc", "1e3"] {
+            client.set(&(key.clone(), value.to_string())).await?;
+
+            let err = client.decr_floor(&key, 1, 0).await.unwrap_err();
+            assert!(err.to_string().contains("not an integer"));
+            assert_eq!(
+                Some(value.to_string()),
+                client.get::<String, _>(&key).await?
+            );
+        }
 
         // Clear
-        client.del(key).await?;
+        client.del(&key).await?;
 
         Ok(())
     }
 
+    // endregion: --- SET TESTS
+
+    // region:    --- DEL TESTS
+
     #[tokio::test]
-    async fn test_redis_mget() -> Result<()> {
+    async fn test_redis_del() -> Result<()> {
         let client = get_client().await;
 
-        let key1 = "test_redis_mget1".to_string();
-        let key2 = "test_redis_mget2".to_string();
+        let key = "test_redis_del".to_string();
+
+        // Create model
+        let fx_model = Tst::default(&key);
+        let tuple = (key.clone(), serde_json::to_string(&fx_model)?);
+        client.mset([&tuple]).await?;
+
+        // Test
+        assert_eq!(Some(fx_model), client.get(&key).await?);
+        assert!(client.del(&key).await?);
+        assert_eq!(None::<Tst>, client.get(&key).await?);
+        assert!(!client.del(&key).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_mdel() -> Result<()> {
+        let client = get_client().await;
+
+        let key1 = "test_redis_mdel1".to_string();
+        let key2 = "test_redis_mdel2".to_string();
 
         // Create models
         let model1 = Tst::default(&key1);
         let model2 = Tst::default(&key2);
 
-        // Используем кортежи для mset
         let tuple1 = (key1.clone(), serde_json::to_string(&model1)?);
         let tuple2 = (key2.clone(), serde_json::to_string(&model2)?);
         client.mset([&tuple1, &tuple2]).await?;
 
-        // Test mget
-        let got: Vec<Option<Tst>> = client.mget(&[&key1, &key2]).await?;
-        assert_eq!(vec![Some(model1), Some(model2)], got);
+        assert_eq!(Some(model1), client.get(&key1).await?);
+        assert_eq!(Some(model2), client.get(&key2).await?);
+
+        // Test
+        assert_eq!(2, client.mdel([&key1, &key2]).await?);
+        assert_eq!(None::<Tst>, client.get(&key1).await?);
+        assert_eq!(None::<Tst>, client.get(&key2).await?);
+        assert_eq!(0, client.mdel([&key1, &key2]).await?);
+
+        Ok(())
+    }
+
+    // endregion: --- DEL TESTS
+
+    // region:    --- EMPTY INPUT TESTS
+
+    #[tokio::test]
+    async fn test_redis_multi_empty_input() -> Result<()> {
+        let mut config = Config::from_url("redis://127.0.0.1:6379");
+        config.pool = Some(deadpool_redis::PoolConfig::new(1));
+
+        let client = Client::connect(&config)
+            .await?
+            .with_pool_get_timeout(Duration::from_millis(100));
+
+        // Hold the only connection, so any call reaching the server fails
+        let _held = client.connection().await?;
+
+        // Test
+        let got: Vec<Option<Tst>> = client.mget(Vec::<String>::new()).await?;
+        assert!(got.is_empty());
+        assert_eq!("OK", client.mset(Vec::<&(String, String)>::new()).await?);
+        assert!(client.mset_nx(Vec::<&(String, String)>::new()).await?);
+        assert_eq!(0, client.mdel(Vec::<String>::new()).await?);
+
+        Ok(())
+    }
+
+    // endregion: --- EMPTY INPUT TESTS
+
+    // region:    --- OTHER TESTS
+
+    #[tokio::test]
+    async fn test_redis_exists() -> Result<()> {
+        let client = get_client().await;
+
+        let key = "test_redis_exists".to_string();
+
+        assert!(!client.exists(&key).await?);
+
+        // Create model
+        let fx_model = Tst::default(&key);
+        let tuple = (key.clone(), serde_json::to_string(&fx_model)?);
+        client.mset([&tuple]).await?;
+
+        assert!(client.exists(&key).await?);
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_wait_for_key() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_wait_for_key_{}", Uuid::new_v4());
+        let poll = Duration::from_millis(10);
+
+        // Test: missing key times out
+        assert!(
+            !client
+                .wait_for_key(&key, Duration::from_millis(50), poll)
+                .await?
+        );
+
+        // Test: key set by another task is observed
+        let writer = client.clone();
+        let writer_key = key.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            writer.set_bytes(&writer_key, b"done").await
+        });
+
+        assert!(
+            client
+                .wait_for_key(&key, Duration::from_secs(5), poll)
+                .await?
+        );
+        handle.await.unwrap()?;
+
+        // Test: present key returns immediately
+        let started = std::time::Instant::now();
+        assert!(
+            client
+                .wait_for_key(&key, Duration::from_secs(5), poll)
+                .await?
+        );
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        // Test: timeout without a representable deadline does not overflow
+        assert!(client.wait_for_key(&key, Duration::MAX, poll).await?);
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        // Clear
+        client.del(&key).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_ping() -> Result<()> {
+        let client = get_client().await;
+        assert_eq!("PONG", client.ping().await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_ping_message() -> Result<()> {
+        let client = get_client().await;
+
+        let msg = Uuid::new_v4().to_string();
+        assert_eq!(msg, client.ping_message(&msg).await?);
+        assert_eq!("PONG", client.ping_message("").await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_with_connection() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_with_connection_{}", Uuid::new_v4());
+
+        // Create model
+        let fx_model = Tst::default(&key);
+        let value = serde_json::to_string(&fx_model)?;
+
+        // Test
+        let (id_before, got, deleted, id_after): (i64, Option<Tst>, bool, i64) = client
+            .with_connection(|connection| {
+                Box::pin(async move {
+                    let id_before = cmd("CLIENT").arg("ID").query_async(connection).await?;
+
+                    let _: () = connection.set(&key, &value).await?;
+                    let got = connection.get(&key).await?;
+                    let deleted = connection.del(&key).await?;
+
+                    let id_after = cmd("CLIENT").arg("ID").query_async(connection).await?;
+
+                    Ok((id_before, got, deleted, id_after))
+                })
+            })
+            .await?;
+
+        assert_eq!(Some(fx_model), got);
+        assert!(deleted);
+        assert_eq!(id_before, id_after);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_latency() -> Result<()> {
+        let client = get_client().await;
+
+        let latency = client.latency().await?;
+        assert!(latency > Duration::ZERO);
+        assert!(latency < Duration::from_secs(1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redis_object_encoding() -> Result<()> {
+        let client = get_client().await;
+
+        let key = format!("test_redis_object_encoding_{}", Uuid::new_v4());
+
+        // Create values
+        client.set(&(key.clone(), "short")).await?;
+
+        // Test
+        let encoding = client.object_encoding(&key).await?.unwrap();
+        assert!(encoding == "embstr" || encoding == "int");
+        assert!(client.object_idletime(&key).await?.is_some());
+
+        client.del(&key).await?;
 
-        // Clear
-        client.mdel([&key1, &key2]).await?;
+        assert_eq!(None, client.object_encoding(&key).await?);
+        assert_eq!(None, client.object_idletime(&key).await?);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_get_ex() -> Result<()> {
+    async fn test_redis_key_type() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_get_ex";
+        let key = format!("test_redis_key_type_{}", Uuid::new_v4());
 
-        // Create model
-        let fx_model = Tst::default(key);
-        client.set(&fx_model).await?;
+        // Test missing key
+        assert_eq!(RedisKeyType::None, client.key_type(&key).await?);
 
-        // Test
-        assert_eq!(Some(fx_model), client.get_ex(key, Expiry::EX(2)).await?);
+        // Test set key
+        let _: usize = client
+            .with_connection(|connection| {
+                let key = key.clone();
+                Box::pin(async move { Ok(connection.sadd(key, "member").await?) })
+            })
+            .await?;
+        assert_eq!(RedisKeyType::Set, client.key_type(&key).await?);
 
-        tokio::time::sleep(Duration::from_secs(3)).await;
+        // Test string key
+        client.del(&key).await?;
+        client.set(&(key.clone(), "value")).await?;
+        assert_eq!(RedisKeyType::String, client.key_type(&key).await?);
 
-        assert_eq!(None::<Tst>, client.get(key).await?);
+        // Clear
+        client.del(&key).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_get_del() -> Result<()> {
-        let client = get_client().await;
+    async fn test_redis_flush_db() -> Result<()> {
+        // Use a separate database, so other tests are not affected
+        let client = Client::from_url("redis://127.0.0.1:6379/15").await?;
+        let other = get_client().await;
 
-        let key = "test_redis_get_del";
+        let key = format!("test_redis_flush_db_{}", Uuid::new_v4());
 
-        // Create model
-        let fx_model = Tst::default(key);
-        client.set(&fx_model).await?;
+        // Create values
+        client.set(&(key.clone(), "value")).await?;
+        other.set(&(key.clone(), "value")).await?;
 
         // Test
-        assert_eq!(Some(fx_model), client.get_del(key).await?);
+        client.flush_db(FlushConfirm::yes_really()).await?;
 
-        assert_eq!(None::<Tst>, client.get(key).await?);
+        let size: usize = client
+            .with_connection(|connection| {
+                Box::pin(async move { Ok(cmd("DBSIZE").query_async(connection).await?) })
+            })
+            .await?;
+        assert_eq!(0, size);
+
+        // Only the selected database is flushed
+        assert!(other.exists(&key).await?);
+
+        // Clear
+        other.del(&key).await?;
 
         Ok(())
     }
 
+    #[test]
+    fn test_redis_key_type_parse() {
+        assert_eq!(RedisKeyType::None, RedisKeyType::from("none"));
+        assert_eq!(RedisKeyType::ZSet, RedisKeyType::from("zset"));
+        assert_eq!(RedisKeyType::Stream, RedisKeyType::from("stream"));
+        assert_eq!(
+            RedisKeyType::Other("ReJSON-RL".to_string()),
+            RedisKeyType::from("ReJSON-RL")
+        );
+    }
+
     #[tokio::test]
-    async fn test_redis_getset() -> Result<()> {
+    async fn test_redis_info() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_getset";
-
-        // Create model
-        let to_get = Tst::default(key);
-        let to_set = Tst::default(key).inc(5);
-
-        client.set(&to_get).await?;
-
         // Test
-        assert_eq!(Some(to_get), client.getset(&to_set).await?);
-        assert_eq!(Some(to_set), client.get(key).await?);
+        let info = client.info(Some("server")).await?;
+        assert!(info.contains_key("redis_version"));
+        assert!(!info.keys().any(|key| key.starts_with('#')));
 
-        // Clear
-        client.del(key).await?;
+        let info = client.info(None).await?;
+        assert!(info.contains_key("redis_version"));
 
         Ok(())
     }
 
-    // endregion: --- GET TESTS
+    #[test]
+    fn test_redis_parse_info() {
+        let raw = "# Server\r\nredis_version:7.2.4\r\nmalformed line\r\n\r\n:no_key\r\nexecutable:/usr/bin/redis-server\r\n# Clients\r\nconnected_clients:1\r\n";
 
-    // region:    --- SET TESTS
+        let info = parse_info(raw);
+
+        assert_eq!(3, info.len());
+        assert_eq!(Some("7.2.4"), info.get("redis_version").map(String::as_str));
+        assert_eq!(
+            Some("/usr/bin/redis-server"),
+            info.get("executable").map(String::as_str)
+        );
+        assert_eq!(Some("1"), info.get("connected_clients").map(String::as_str));
+    }
 
     #[tokio::test]
-    async fn test_redis_set() -> Result<()> {
+    async fn test_redis_rename() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_set";
+        let key = "test_redis_rename".to_string();
+        let new_key = "test_redis_rename_new".to_string();
 
         // Create model
-        let fx_model = Tst::default(key);
+        let fx_key_model = Tst::default(&key);
+        let tuple = (key.clone(), serde_json::to_string(&fx_key_model)?);
+        client.mset([&tuple]).await?;
 
-        // Test
-        assert_eq!("OK", client.set(&fx_model).await?);
-        assert_eq!(Some(fx_model), client.get(key).await?);
+        assert_eq!("OK", client.rename(&key, &new_key).await?);
+
+        let res: Option<Tst> = client.get(&key).await?;
+        assert_eq!(None, res);
+        assert_eq!(Some(fx_key_model), client.get(&new_key).await?);
 
         // Clear
-        client.del(key).await?;
+        client.del(&new_key).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_mset() -> Result<()> {
+    async fn test_redis_expire_at() -> Result<()> {
         let client = get_client().await;
 
-        let key1 = "test_redis_mset1".to_string();
-        let key2 = "test_redis_mset2".to_string();
+        let key = format!("test_redis_expire_at_{}", Uuid::new_v4());
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
 
-        // Create models
-        let model1 = Tst::default(&key1);
-        let model2 = Tst::default(&key2);
+        // Test: missing key
+        assert!(!client.expire_at(&key, now + 60).await?);
 
-        // Используем кортежи для mset
-        let tuple1 = (key1.clone(), serde_json::to_string(&model1)?);
-        let tuple2 = (key2.clone(), serde_json::to_string(&model2)?);
+        // Test: key survives until the timestamp
+        client.set(&Tst::default(&key)).await?;
+        assert!(client.expire_at(&key, now + 2).await?);
+        assert!(client.exists(&key).await?);
 
-        assert_eq!("OK", client.mset([&tuple1, &tuple2]).await?);
+        let mut connection = client.connection().await?;
+        let ttl: i64 = connection.ttl(&key).await?;
+        assert!((0..=2).contains(&ttl));
 
-        assert_eq!(Some(model1), client.get(&key1).await?);
-        assert_eq!(Some(model2), client.get(&key2).await?);
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert!(!client.exists(&key).await?);
 
-        // Clear
-        client.mdel([&key1, &key2]).await?;
+        // Test: timestamp in the past deletes the key immediately
+        client.set(&Tst::default(&key)).await?;
+        assert!(client.expire_at(&key, now - 60).await?);
+        assert!(!client.exists(&key).await?);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_set_ex() -> Result<()> {
+    async fn test_redis_rename_nx() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_set_ex";
+        let key = "test_redis_rename_nx".to_string();
+        let new_key = "test_redis_rename_nx_new".to_string();
 
         // Create model
-        let fx_model = Tst::default(key);
+        let fx_key_model = Tst::default(&key);
+        let tuple = (key.clone(), serde_json::to_string(&fx_key_model)?);
+        client.mset([&tuple]).await?;
 
         // Test
-        assert_eq!("OK", client.set_ex(&fx_model, 2).await?);
+        assert!(client.rename_nx(&key, &new_key).await?);
 
-        assert_eq!(Some(fx_model), client.get(key).await?);
-        tokio::time::sleep(Duration::from_secs(3)).await;
-        assert_eq!(None::<Tst>, client.get(key).await?);
+        let res: Option<Tst> = client.get(&key).await?;
+        assert_eq!(None, res);
+        assert_eq!(Some(fx_key_model.clone()), client.get(&new_key).await?);
+
+        let fx_key_new_model = Tst::default(&key);
+        let tuple2 = (key.clone(), serde_json::to_string(&fx_key_new_model)?);
+        client.mset([&tuple2]).await?;
+
+        assert!(!client.rename_nx(&new_key, &key).await?);
 
         // Clear
-        client.del(key).await?;
+        client.mdel([&key, &new_key]).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_set_nx() -> Result<()> {
+    async fn test_redis_set_store() -> Result<()> {
         let client = get_client().await;
 
-        let key = "test_redis_set_nx1".to_string();
-
-        // Create models
-        let model1 = Tst::default(&key);
-        let model2 = Tst::default(&key).inc(5);
+        let id = Uuid::new_v4();
+        let key1 = format!("test_redis_set_store1_{id}");
+        let key2 = format!("test_redis_set_store2_{id}");
+        let dest = format!("test_redis_set_store_dest_{id}");
 
-        // Используем кортежи для set_nx
-        let _tuple1 = (key.clone(), serde_json::to_string(&model1)?);
-        let _tuple2 = (key.clone(), serde_json::to_string(&model2)?);
+        // Create sets
+        let mut connection = client.connection().await?;
+        let _: () = connection.sadd(&key1, &["a", "b", "c"]).await?;
+        let _: () = connection.sadd(&key2, &["b", "c", "d"]).await?;
 
         // Test
-        assert!(client.set_nx(&model1).await?);
-        assert_eq!(Some(model1.clone()), client.get(&key).await?);
+        assert_eq!(2, client.sinterstore(&dest, &[&key1, &key2]).await?);
+        let mut members: Vec<String> = connection.smembers(&dest).await?;
+        members.sort();
+        assert_eq!(vec!["b", "c"], members);
 
-        assert!(!client.set_nx(&model2).await?);
-        assert_eq!(Some(model1), client.get(&key).await?);
+        assert_eq!(4, client.sunionstore(&dest, &[&key1, &key2]).await?);
+
+        assert_eq!(1, client.sdiffstore(&dest, &[&key1, &key2]).await?);
+        let members: Vec<String> = connection.smembers(&dest).await?;
+        assert_eq!(vec!["a"], members);
 
         // Clear
-        client.del(&key).await?;
+        client.mdel([&key1, &key2, &dest]).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_mset_nx() -> Result<()> {
+    async fn test_redis_smismember() -> Result<()> {
         let client = get_client().await;
 
-        let key1 = format!("test_redis_mset_nx1_{}", Uuid::new_v4());
-        let key2 = format!("test_redis_mset_nx2_{}", Uuid::new_v4());
-
-        // Create models
-        let model1_before = Tst::default(&key1);
-        let model2_before = Tst::default(&key2);
-        let model1_after = Tst::default(&key1).inc(3);
-        let model2_after = Tst::default(&key2).inc(3);
-
-        // Используем кортежи для mset_nx
-        let tuple1_before = (key1.clone(), serde_json::to_string(&model1_before)?);
-        let tuple2_before = (key2.clone(), serde_json::to_string(&model2_before)?);
-        let tuple1_after = (key1.clone(), serde_json::to_string(&model1_after)?);
-        let tuple2_after = (key2.clone(), serde_json::to_string(&model2_after)?);
+        let key = format!("test_redis_smismember_{}", Uuid::new_v4());
 
-        // Test
-        assert!(client.mset_nx([&tuple1_before, &tuple2_before]).await?);
+        // Test: missing set
         assert_eq!(
-            vec![Some(model1_before.clone()), Some(model2_before.clone())],
-            client.mget(&[&key1, &key2]).await?
+            vec![false, false, false],
+            client.smismember(&key, &["a", "b", "c"]).await?
         );
 
-        assert!(!client.mset_nx([&tuple1_after, &tuple2_after]).await?);
+        // Create set
+        let mut connection = client.connection().await?;
+        let _: () = connection.sadd(&key, &["a", "c"]).await?;
+
+        // Test
         assert_eq!(
-            vec![Some(model1_before), Some(model2_before)],
-            client.mget(&[&key1, &key2]).await?
+            vec![true, false, true],
+            client.smismember(&key, &["a", "b", "c"]).await?
         );
+        assert!(client.smismember::<_, &str>(&key, &[]).await?.is_empty());
 
         // Clear
-        client.mdel([&key1, &key2]).await?;
+        client.del(&key).await?;
 
         Ok(())
     }
 
-    // endregion: --- SET TESTS
+    // endregion: --- OTHER TESTS
 
-    // region:    --- DEL TESTS
+    // region:    --- SLOW TESTS
 
     #[tokio::test]
-    async fn test_redis_del() -> Result<()> {
-        let client = get_client().await;
+    async fn test_redis_warn_slow_acquire() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
 
-        let key = "test_redis_del".to_string();
-
-        // Create model
-        let fx_model = Tst::default(&key);
-        let tuple = (key.clone(), serde_json::to_string(&fx_model)?);
-        client.mset([&tuple]).await?;
+        let client = get_client().await.warn_slow_acquire(Duration::ZERO);
 
         // Test
-        assert_eq!(Some(fx_model), client.get(&key).await?);
-        assert!(client.del(&key).await?);
-        assert_eq!(None::<Tst>, client.get(&key).await?);
-        assert!(!client.del(&key).await?);
+        client.ping().await?;
+        assert!(logs.contents().contains("Slow connection acquire"));
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_mdel() -> Result<()> {
-        let client = get_client().await;
-
-        let key1 = "test_redis_mdel1".to_string();
-        let key2 = "test_redis_mdel2".to_string();
-
-        // Create models
-        let model1 = Tst::default(&key1);
-        let model2 = Tst::default(&key2);
-
-        let tuple1 = (key1.clone(), serde_json::to_string(&model1)?);
-        let tuple2 = (key2.clone(), serde_json::to_string(&model2)?);
-        client.mset([&tuple1, &tuple2]).await?;
+    async fn test_redis_warn_slow_command() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
 
-        assert_eq!(Some(model1), client.get(&key1).await?);
-        assert_eq!(Some(model2), client.get(&key2).await?);
+        let client = get_client().await.warn_slow_command(Duration::ZERO);
 
         // Test
-        assert_eq!(2, client.mdel([&key1, &key2]).await?);
-        assert_eq!(None::<Tst>, client.get(&key1).await?);
-        assert_eq!(None::<Tst>, client.get(&key2).await?);
-        assert_eq!(0, client.mdel([&key1, &key2]).await?);
+        client.ping().await?;
+        assert!(logs.contents().contains("Slow command PING"));
 
         Ok(())
     }
 
-    // endregion: --- DEL TESTS
-
-    // region:    --- OTHER TESTS
-
     #[tokio::test]
-    async fn test_redis_exists() -> Result<()> {
-        let client = get_client().await;
-
-        let key = "test_redis_exists".to_string();
-
-        assert!(!client.exists(&key).await?);
+    async fn test_redis_pool_get_timeout() -> Result<()> {
+        let mut config = Config::from_url("redis://127.0.0.1:6379");
+        config.pool = Some(deadpool_redis::PoolConfig::new(1));
 
-        // Create model
-        let fx_model = Tst::default(&key);
-        let tuple = (key.clone(), serde_json::to_string(&fx_model)?);
-        client.mset([&tuple]).await?;
+        let client = Client::connect(&config)
+            .await?
+            .with_pool_get_timeout(Duration::from_millis(100));
 
-        assert!(client.exists(&key).await?);
+        // Hold the only connection
+        let _held = client.connection().await?;
 
-        // Clear
-        client.del(&key).await?;
+        // Test
+        let err = client.connection().await.unwrap_err();
+        assert!(err.is_pool_timeout());
 
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_redis_ping() -> Result<()> {
-        let client = get_client().await;
-        assert_eq!("PONG", client.ping().await?);
-        Ok(())
-    }
+    // endregion: --- SLOW TESTS
+
+    // region:    --- SCAN TESTS
 
     #[tokio::test]
-    async fn test_redis_rename() -> Result<()> {
+    async fn test_redis_scan_pages() -> Result<()> {
+        use crate::Pagable;
+
         let client = get_client().await;
 
-        let key = "test_redis_rename".to_string();
-        let new_key = "test_redis_rename_new".to_string();
+        let prefix = format!("test_redis_scan_pages_{}", Uuid::new_v4());
+        let keys = (0..12)
+            .map(|i| format!("{prefix}:{i}"))
+            .collect::<Vec<String>>();
 
-        // Create model
-        let fx_key_model = Tst::default(&key);
-        let tuple = (key.clone(), serde_json::to_string(&fx_key_model)?);
-        client.mset([&tuple]).await?;
+        // Create keys
+        for key in &keys {
+            client.set(&(key.clone(), 1)).await?;
+        }
 
-        assert_eq!("OK", client.rename(&key, &new_key).await?);
+        // Test
+        let mut pages = client.scan_pages(Some(&format!("{prefix}:*")), 5).await?;
+        let mut got = vec![];
 
-        let res: Option<Tst> = client.get(&key).await?;
-        assert_eq!(None, res);
-        assert_eq!(Some(fx_key_model), client.get(&new_key).await?);
+        let page = pages.next_page().await.unwrap();
+        assert_eq!(5, page.len());
+        got.extend_from_slice(page);
+
+        let page = pages.next_page().await.unwrap();
+        assert_eq!(5, page.len());
+        got.extend_from_slice(page);
+
+        let page = pages.next_page().await.unwrap();
+        assert_eq!(2, page.len());
+        got.extend_from_slice(page);
+
+        assert!(pages.next_page().await.is_none());
+
+        got.sort();
+        let mut expected = keys.clone();
+        expected.sort();
+        assert_eq!(expected, got);
 
         // Clear
-        client.del(&new_key).await?;
+        client.mdel(&keys).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_redis_rename_nx() -> Result<()> {
+    async fn test_redis_scan_pages_owned() -> Result<()> {
+        use crate::Pagable;
+
         let client = get_client().await;
 
-        let key = "test_redis_rename_nx".to_string();
-        let new_key = "test_redis_rename_nx_new".to_string();
+        let prefix = format!("test_redis_scan_pages_owned_{}", Uuid::new_v4());
+        let keys = (0..12)
+            .map(|i| format!("{prefix}:{i}"))
+            .collect::<Vec<String>>();
 
-        // Create model
-        let fx_key_model = Tst::default(&key);
-        let tuple = (key.clone(), serde_json::to_string(&fx_key_model)?);
-        client.mset([&tuple]).await?;
+        // Create keys
+        for key in &keys {
+            client.set(&(key.clone(), 1)).await?;
+        }
 
         // Test
-        assert!(client.rename_nx(&key, &new_key).await?);
-
-        let res: Option<Tst> = client.get(&key).await?;
-        assert_eq!(None, res);
-        assert_eq!(Some(fx_key_model.clone()), client.get(&new_key).await?);
+        let mut pages = client.scan_pages(Some(&format!("{prefix}:*")), 5).await?;
+        let mut got: Vec<Vec<String>> = vec![];
 
-        let fx_key_new_model = Tst::default(&key);
-        let tuple2 = (key.clone(), serde_json::to_string(&fx_key_new_model)?);
-        client.mset([&tuple2]).await?;
+        while let Some(page) = pages.next_page_owned().await {
+            got.push(page);
+        }
 
-        assert!(!client.rename_nx(&new_key, &key).await?);
+        assert_eq!(3, got.len());
+        assert_eq!(
+            vec![5, 5, 2],
+            got.iter().map(|page| page.len()).collect::<Vec<_>>()
+        );
 
         // Clear
-        client.mdel([&key, &new_key]).await?;
+        client.mdel(&keys).await?;
 
         Ok(())
     }
 
-    // endregion: --- OTHER TESTS
+    // endregion: --- SCAN TESTS
 }
 
 // endregion: --- Tests
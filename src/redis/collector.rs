@@ -46,3 +46,33 @@ where
         self.iter().map(|m| (m.key_ref(), m.value_ref())).collect()
     }
 }
+
+// Реализация для Vec моделей во владении (без Clone)
+impl<M> AsRedisPairs<M> for Vec<M>
+where
+    M: RedisModel,
+{
+    fn as_pairs(&self) -> Vec<(&M::Key, &M::Value)> {
+        self.iter().map(|m| (m.key_ref(), m.value_ref())).collect()
+    }
+}
+
+// Реализация для среза моделей во владении
+impl<'a, M> AsRedisPairs<M> for &'a [M]
+where
+    M: RedisModel,
+{
+    fn as_pairs(&self) -> Vec<(&M::Key, &M::Value)> {
+        self.iter().map(|m| (m.key_ref(), m.value_ref())).collect()
+    }
+}
+
+/// Collects references to models from any iterator, so the result can be passed to
+/// `mset`/`mset_nx`, e.g. `client.mset(collect_iter(models.iter().filter(..)))`.
+pub fn collect_iter<'a, M, I>(iter: I) -> Vec<&'a M>
+where
+    M: RedisModel + 'a,
+    I: IntoIterator<Item = &'a M>,
+{
+    iter.into_iter().collect()
+}
@@ -0,0 +1,174 @@
+//! A module for compressing values stored in Redis.
+//!
+//! Requires the `compression` feature.
+//!
+//! Large JSON values waste Redis memory. When a client is configured with
+//! `Client::with_compression`, values written by `set`, `set_ex` and `set_many`
+//! are compressed with the chosen `Codec`. Compressed values start with a magic
+//! prefix, so reads detect and decompress them transparently, and clients can
+//! read keys holding both compressed and uncompressed values.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use grapple_db::redis::{Client, Codec};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::default().await?.with_compression(Codec::Zstd);
+//!
+//!     let report = ("report:1".to_string(), "a large value".repeat(1000));
+//!     client.set(&report).await?;
+//!
+//!     let report: Option<String> = client.get("report:1").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use super::{Error, Result};
+use flate2::{read::GzDecoder, write::GzEncoder};
+use std::io::{Read, Write};
+
+/// Prefix marking a compressed value, followed by the id of the codec.
+///
+/// Starts with a NUL byte, which never starts a JSON document or a text value.
+const MAGIC: &[u8] = b"\0GDBZ";
+
+/// A compression algorithm for values stored in Redis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// Gzip compression, widely supported and fast to decompress.
+    Gzip,
+    /// Zstandard compression, usually faster and smaller than gzip.
+    Zstd,
+}
+
+impl Codec {
+    /// Returns the id of the codec stored after the magic prefix.
+    fn id(self) -> u8 {
+        match self {
+            Codec::Gzip => 1,
+            Codec::Zstd => 2,
+        }
+    }
+
+    /// Returns the codec with the given id, if any.
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Codec::Gzip),
+            2 => Some(Codec::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Compresses a value and prepends the magic prefix.
+///
+/// # Arguments
+///
+/// * `codec` - The compression algorithm to use.
+/// * `bytes` - The value to compress.
+///
+/// # Returns
+///
+/// A `Result` containing the prefixed compressed value.
+pub fn compress(codec: Codec, bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut compressed = MAGIC.to_vec();
+    compressed.push(codec.id());
+
+    match codec {
+        Codec::Gzip => {
+            let mut encoder = GzEncoder::new(compressed, flate2::Compression::default());
+            encoder.write_all(bytes)?;
+            Ok(encoder.finish()?)
+        }
+        Codec::Zstd => {
+            zstd::stream::copy_encode(bytes, &mut compressed, 0)?;
+            Ok(compressed)
+        }
+    }
+}
+
+/// Decompresses a value if it starts with the magic prefix.
+///
+/// # Arguments
+///
+/// * `bytes` - The value as stored in Redis.
+///
+/// # Returns
+///
+/// A `Result` containing the decompressed value, or the value unchanged if it
+/// isn't compressed. An unknown codec id fails with `Error::Compression`.
+pub fn decompress(bytes: Vec<u8>) -> Result<Vec<u8>> {
+    let Some(rest) = bytes.strip_prefix(MAGIC) else {
+        return Ok(bytes);
+    };
+
+    let Some((&id, compressed)) = rest.split_first() else {
+        return Ok(bytes);
+    };
+
+    let Some(codec) = Codec::from_id(id) else {
+        return Err(Error::Compression(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("unknown compression codec {id}"),
+        )));
+    };
+
+    let mut decompressed = Vec::new();
+
+    match codec {
+        Codec::Gzip => {
+            GzDecoder::new(compressed).read_to_end(&mut decompressed)?;
+        }
+        Codec::Zstd => {
+            zstd::stream::copy_decode(compressed, &mut decompressed)?;
+        }
+    }
+
+    Ok(decompressed)
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_roundtrip() -> Result<()> {
+        let value = r#"{"id":"1","name":"test"}"#.repeat(100);
+
+        for codec in [Codec::Gzip, Codec::Zstd] {
+            let compressed = compress(codec, value.as_bytes())?;
+            assert!(compressed.starts_with(MAGIC));
+            assert!(compressed.len() < value.len());
+
+            assert_eq!(value.as_bytes(), decompress(compressed)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compression_plain_passthrough() -> Result<()> {
+        let value = br#"{"id":"1"}"#.to_vec();
+        assert_eq!(value, decompress(value.clone())?);
+
+        // Bare prefix without a codec id is not a compressed value
+        assert_eq!(MAGIC, decompress(MAGIC.to_vec())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compression_unknown_codec() {
+        let mut value = MAGIC.to_vec();
+        value.extend([42, 1, 2, 3]);
+
+        assert!(matches!(decompress(value), Err(Error::Compression(_))));
+    }
+}
+
+// endregion: --- Tests
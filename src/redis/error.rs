@@ -14,6 +14,18 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// - `CreatePoolError` - Represents an error that occurs when creating a connection pool.
 /// - `PoolError` - Represents an error that occurs while interacting with the connection pool.
 /// - `Redis` - Represents an error that originates from the Redis library during operations.
+/// - `WrongType` - Represents a `WRONGTYPE` error returned by Redis when a command is
+///   executed against a key holding a value of a different type (e.g. `GET` on a hash).
+/// - `InvalidKey` - Represents a key rejected by validation before reaching Redis,
+///   e.g. an empty key. Contains the reason of the rejection.
+/// - `InvalidTtl` - Represents a TTL rejected before reaching Redis, e.g. a TTL of 0 seconds
+///   or a TTL overflowing the expiration time supported by Redis.
+/// - `DuplicateKeys` - Represents a batch containing the same key more than once.
+///   Contains the duplicated keys.
+/// - `ResponseLength` - Represents a response whose number of elements doesn't match the
+///   number of requested keys.
+/// - `Compression` - Represents an error that occurs while compressing or decompressing a value,
+///   e.g. a corrupted compressed value.
 /// - `Serde` - Represents an error that occurs during serialization or deserialization of data
 ///   using the Serde library.
 #[derive(Debug, From)]
@@ -24,9 +36,24 @@ pub enum Error {
     #[from]
     PoolError(deadpool_redis::PoolError),
 
-    #[from]
     Redis(super::RedisError),
 
+    WrongType(super::RedisError),
+
+    InvalidKey(String),
+
+    InvalidTtl(u64),
+
+    DuplicateKeys(Vec<String>),
+
+    ResponseLength {
+        expected: usize,
+        got: usize,
+    },
+
+    #[from]
+    Compression(std::io::Error),
+
     #[from]
     Serde(serde_json::Error),
 }
@@ -49,6 +76,34 @@ impl Serialize for Error {
                 // Serialize the Redis error as a string
                 serializer.serialize_str(&redis_error.to_string())
             }
+            Error::WrongType(redis_error) => {
+                // Serialize the WrongType error as a string
+                serializer.serialize_str(&redis_error.to_string())
+            }
+            Error::InvalidKey(reason) => {
+                // Serialize the InvalidKey error as a string
+                serializer.serialize_str(&format!("Invalid key: {reason}"))
+            }
+            Error::InvalidTtl(secs) => {
+                // Serialize the InvalidTtl error as a string
+                serializer.serialize_str(&format!(
+                    "Invalid TTL: {secs} seconds, expected a non-zero TTL within the range supported by Redis"
+                ))
+            }
+            Error::DuplicateKeys(keys) => {
+                // Serialize the DuplicateKeys error as a string
+                serializer.serialize_str(&format!("Duplicate keys: {}", keys.join(", ")))
+            }
+            Error::ResponseLength { expected, got } => {
+                // Serialize the ResponseLength error as a string
+                serializer.serialize_str(&format!(
+                    "Response length mismatch: expected {expected}, got {got}"
+                ))
+            }
+            Error::Compression(io_error) => {
+                // Serialize the Compression error as a string
+                serializer.serialize_str(&format!("Compression error: {io_error}"))
+            }
             Error::Serde(serde_error) => {
                 // Serialize the Serde error as a string
                 serializer.serialize_str(&serde_error.to_string())
@@ -57,6 +112,39 @@ impl Serialize for Error {
     }
 }
 
+impl Error {
+    /// Returns `true` if the error is caused by a type mismatch between the command and the
+    /// value stored at the key (Redis `WRONGTYPE` error).
+    ///
+    /// This happens, for example, when a key holding a hash is read with `get`.
+    pub fn is_type_error(&self) -> bool {
+        matches!(self, Error::WrongType(_))
+    }
+
+    /// Returns `true` if the error is caused by a timeout while waiting for a connection
+    /// from the pool.
+    ///
+    /// See `Client::with_pool_get_timeout`.
+    pub fn is_pool_timeout(&self) -> bool {
+        matches!(
+            self,
+            Error::PoolError(deadpool_redis::PoolError::Timeout(_))
+        )
+    }
+}
+
+/// Converts a `RedisError` into an `Error`, classifying `WRONGTYPE` errors
+/// into the dedicated `Error::WrongType` variant.
+impl From<super::RedisError> for Error {
+    fn from(value: super::RedisError) -> Self {
+        if value.code() == Some("WRONGTYPE") {
+            Error::WrongType(value)
+        } else {
+            Error::Redis(value)
+        }
+    }
+}
+
 // region:    --- Error Boilerplate
 
 impl core::fmt::Display for Error {
@@ -0,0 +1,120 @@
+//! A module for building `Expiry` values from standard time types.
+//!
+//! `Client::get_ex` takes a `deadpool_redis::redis::Expiry`, which only accepts raw
+//! numbers of seconds or milliseconds. The helpers in this module build an `Expiry`
+//! from a `Duration` or a `SystemTime`, choosing the unit automatically.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use grapple_db::redis::{expiry_from_duration, Client};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::default().await?;
+//!
+//!     // Refresh the session for another 30 minutes while reading it
+//!     let expiry = expiry_from_duration(Duration::from_secs(30 * 60));
+//!     let session: Option<String> = client.get_ex("session:1", expiry).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use deadpool_redis::redis::Expiry;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Builds a relative `Expiry` from a `Duration`.
+///
+/// Durations of whole seconds map to `EX`, others to `PX`. A sub-millisecond
+/// remainder is rounded up, so a non-zero duration never maps to a zero expiry.
+/// Durations whose milliseconds don't fit into `u64` are clamped to `PX(u64::MAX)`.
+/// Note that Redis rejects a zero expiry, which is what `Duration::ZERO` maps to.
+///
+/// # Arguments
+///
+/// * `duration` - The time after which the key expires.
+///
+/// # Returns
+///
+/// The corresponding `Expiry`.
+pub fn expiry_from_duration(duration: Duration) -> Expiry {
+    if duration.subsec_nanos() == 0 {
+        return Expiry::EX(duration.as_secs());
+    }
+
+    let millis = duration.as_nanos().div_ceil(1_000_000);
+
+    Expiry::PX(u64::try_from(millis).unwrap_or(u64::MAX))
+}
+
+/// Builds an absolute `Expiry` from a point in time.
+///
+/// The time is sent with millisecond precision as `PXAT`. Times before the Unix
+/// epoch are clamped to the epoch, which expires the key immediately.
+///
+/// # Arguments
+///
+/// * `time` - The point in time at which the key expires.
+///
+/// # Returns
+///
+/// The corresponding `Expiry`.
+pub fn expiry_at(time: SystemTime) -> Expiry {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis());
+
+    Expiry::PXAT(u64::try_from(millis).unwrap_or(u64::MAX))
+}
+
+/// Builds an `Expiry` removing the time-to-live of the key.
+///
+/// # Returns
+///
+/// `Expiry::PERSIST`.
+pub fn expiry_persist() -> Expiry {
+    Expiry::PERSIST
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_from_duration() {
+        assert!(matches!(
+            expiry_from_duration(Duration::from_millis(1500)),
+            Expiry::PX(1500)
+        ));
+        assert!(matches!(
+            expiry_from_duration(Duration::from_secs(3)),
+            Expiry::EX(3)
+        ));
+        assert!(matches!(
+            expiry_from_duration(Duration::from_nanos(1)),
+            Expiry::PX(1)
+        ));
+        assert!(matches!(
+            expiry_from_duration(Duration::MAX),
+            Expiry::PX(u64::MAX)
+        ));
+    }
+
+    #[test]
+    fn test_expiry_at() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+        assert!(matches!(expiry_at(time), Expiry::PXAT(1_700_000_000_123)));
+        assert!(matches!(
+            expiry_at(UNIX_EPOCH - Duration::from_secs(1)),
+            Expiry::PXAT(0)
+        ));
+        assert!(matches!(expiry_persist(), Expiry::PERSIST));
+    }
+}
+
+// endregion: --- Tests
@@ -5,35 +5,203 @@
 
 mod client;
 pub mod collector;
+#[cfg(feature = "compression")]
+pub mod compression;
 mod error;
+pub mod expiry;
+pub mod scan;
 
 pub mod pool {
     pub use deadpool_redis::*;
 }
 
 pub mod macros {
+    pub use crate::redis_model;
     pub use grapple_redis_macros::*;
 }
 
 pub use client::Client;
+#[cfg(feature = "compression")]
+pub use compression::Codec;
 pub use deadpool_redis::redis::FromRedisValue;
 pub use deadpool_redis::redis::*;
 pub use error::{Error, Result};
+pub use expiry::{expiry_at, expiry_from_duration, expiry_persist};
 
 use serde::{de::DeserializeOwned, Serialize};
 use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 // Базовый трейт для моделей, которые можно сохранять
 pub trait RedisModel: Serialize {
     type Key: ToRedisArgs + Send + Sync;
     type Value: ToRedisArgs + Send + Sync;
 
+    /// Namespace prepended to the key of the model, e.g. `"user:"`.
+    ///
+    /// Methods storing models (`set`, `mset`, `set_ex`, ...) prepend it automatically, so a model
+    /// with the key `"42"` is stored under `"user:42"`. Defaults to `""` (no prefix).
+    ///
+    /// The client has no prefix of its own, so the stored key is always `PREFIX` followed by the
+    /// key. Methods taking a key rather than a model (`get`, `del`, `exists`, ...) don't apply
+    /// `PREFIX`: pass them the full key, e.g. the one returned by `prefixed_key`.
+    const PREFIX: &'static str = "";
+
     fn key(&self) -> Result<Self::Key>;
     fn key_ref(&self) -> &Self::Key;
     fn value(&self) -> Result<impl ToRedisArgs + Send + Sync> {
         Ok(serde_json::to_string(&self)?)
     }
     fn value_ref(&self) -> &Self::Value;
+
+    /// Returns the key of the model with `PREFIX` prepended, as it is stored in Redis.
+    fn prefixed_key(&self) -> Result<Vec<u8>> {
+        Ok(prefixed_key(Self::PREFIX, &self.key()?))
+    }
+
+    /// Validates the key of the model before it is sent to Redis.
+    ///
+    /// The default implementation uses `validate_key`, rejecting empty keys and text keys
+    /// containing whitespace or control characters. Override it to relax or tighten the rules.
+    fn validate_key(&self) -> Result<()> {
+        validate_key(&self.key()?)
+    }
+
+    /// Default time-to-live of the model in seconds.
+    ///
+    /// When `Some`, `Client::set` stores the model with `SETEX` using this TTL.
+    /// A TTL of 0 or beyond the range supported by Redis is rejected with `Error::InvalidTtl`
+    /// (see `validate_ttl`).
+    /// An explicit TTL passed to `Client::set_ex` takes precedence.
+    /// Defaults to `None`, meaning the model does not expire.
+    fn ttl_secs(&self) -> Option<u64> {
+        None
+    }
+
+    /// Builds the model from its raw value as stored in Redis, used by `Client::get_model`.
+    ///
+    /// The default implementation deserializes the JSON produced by `value`. When the shape of
+    /// a model changes, override it to upgrade values stored in an older format: attempt the
+    /// current format first and fall back to the older ones. Data matching no format should
+    /// still be reported as an error.
+    ///
+    /// Only `Client::get_model` calls it. `Client::get` accepts any `RedisRead` type, not just
+    /// models, and builds values with `FromRedisValue`, so it never migrates: read models that
+    /// override this method with `get_model` instead.
+    fn migrate(raw: &[u8]) -> Result<Self>
+    where
+        Self: Sized + DeserializeOwned,
+    {
+        Ok(serde_json::from_slice(raw)?)
+    }
+}
+
+/// Validates a key before it is sent to Redis.
+///
+/// Empty keys are rejected. Keys that are valid UTF-8 text are also rejected if they contain
+/// whitespace or control characters, which usually indicates a formatting bug. Namespacing
+/// separators such as colons are allowed. Binary keys that are not valid UTF-8 are only checked
+/// for emptiness.
+///
+/// # Returns
+///
+/// `Ok(())` if the key is valid, or `Error::InvalidKey` describing the problem.
+pub fn validate_key<K: ToRedisArgs>(key: &K) -> Result<()> {
+    let args = key.to_redis_args();
+
+    if args.is_empty() || args.iter().any(|arg| arg.is_empty()) {
+        return Err(Error::InvalidKey("key is empty".to_string()));
+    }
+
+    for arg in &args {
+        if let Ok(text) = std::str::from_utf8(arg) {
+            if text.chars().any(|c| c.is_whitespace() || c.is_control()) {
+                return Err(Error::InvalidKey(format!(
+                    "key {text:?} contains whitespace or control characters"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// An explicit confirmation required by `Client::flush_db`.
+///
+/// The type doesn't implement `Default` and can only be created with `yes_really`,
+/// so flushing a database can't happen by accident.
+#[derive(Debug)]
+pub struct FlushConfirm(());
+
+impl FlushConfirm {
+    /// Confirms that all keys of the selected database may be deleted.
+    pub fn yes_really() -> Self {
+        Self(())
+    }
+}
+
+/// The type of the value stored at a key, as reported by the `TYPE` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedisKeyType {
+    /// The key does not exist.
+    None,
+    String,
+    List,
+    Set,
+    ZSet,
+    Hash,
+    Stream,
+    /// A type not known to this enum, e.g. a type added by a Redis module.
+    Other(String),
+}
+
+impl From<&str> for RedisKeyType {
+    /// Parses a reply of the `TYPE` command.
+    fn from(reply: &str) -> Self {
+        match reply {
+            "none" => RedisKeyType::None,
+            "string" => RedisKeyType::String,
+            "list" => RedisKeyType::List,
+            "set" => RedisKeyType::Set,
+            "zset" => RedisKeyType::ZSet,
+            "hash" => RedisKeyType::Hash,
+            "stream" => RedisKeyType::Stream,
+            other => RedisKeyType::Other(other.to_string()),
+        }
+    }
+}
+
+/// Validates a TTL in seconds before it is sent to Redis.
+///
+/// Redis rejects a TTL of 0, as well as a TTL whose expiration time in milliseconds since the
+/// Unix epoch doesn't fit into a signed 64-bit integer. Such TTLs are rejected here, so the
+/// error is descriptive and the command isn't sent.
+///
+/// # Returns
+///
+/// `Ok(())` if the TTL is valid, or `Error::InvalidTtl` with the rejected TTL.
+pub fn validate_ttl(secs: u64) -> Result<()> {
+    let now_millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64);
+    let max_secs = (i64::MAX as u64).saturating_sub(now_millis) / 1000;
+
+    if secs == 0 || secs > max_secs {
+        return Err(Error::InvalidTtl(secs));
+    }
+
+    Ok(())
+}
+
+/// Prepends a prefix to a key, returning the binary key as it is stored in Redis.
+pub(crate) fn prefixed_key<K: ToRedisArgs + ?Sized>(prefix: &str, key: &K) -> Vec<u8> {
+    let mut prefixed = prefix.as_bytes().to_vec();
+
+    for arg in key.to_redis_args() {
+        prefixed.extend(arg);
+    }
+
+    prefixed
 }
 
 // Трейт для типов, которые можно читать из Redis
@@ -70,6 +238,37 @@ where
     }
 }
 
+// Реализация для кортежей с TTL в секундах: (key, value, ttl)
+impl<K, V> RedisModel for (K, V, u64)
+where
+    K: ToRedisArgs + Send + Sync + Clone + Serialize,
+    V: ToRedisArgs + Send + Sync + Serialize,
+    for<'a> &'a V: ToRedisArgs,
+{
+    type Key = K;
+    type Value = V;
+
+    fn key(&self) -> Result<Self::Key> {
+        Ok(self.0.clone())
+    }
+
+    fn key_ref(&self) -> &Self::Key {
+        &self.0
+    }
+
+    fn value(&self) -> Result<impl ToRedisArgs + Send + Sync> {
+        Ok(&self.1)
+    }
+
+    fn value_ref(&self) -> &Self::Value {
+        &self.1
+    }
+
+    fn ttl_secs(&self) -> Option<u64> {
+        Some(self.2)
+    }
+}
+
 /// Обертка для пары ссылок (key, value) - если нужны явные ссылки
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct RedisPairRef<'a, K, V> {
@@ -109,3 +308,65 @@ where
         self.value
     }
 }
+
+/// Implements `RedisModel` for a struct, using one of its fields as the key.
+///
+/// This removes the boilerplate of implementing `key()` and `key_ref()` by hand. The value
+/// is the JSON representation of the whole struct, as with the default `RedisModel::value`.
+/// Like hand-written struct models, `value_ref` returns an empty placeholder, so batch methods
+/// relying on it (`mset`, `mset_nx`) should be used with tuples instead.
+///
+/// # Arguments
+///
+/// * `$model` - The struct to implement `RedisModel` for.
+/// * `key = $field: $key` - The field used as the key and its type, which must implement `Clone`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use grapple_db::redis::macros::{redis_model, FromRedisValue};
+/// use grapple_db::redis::Client;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, FromRedisValue)]
+/// struct Session {
+///     id: String,
+///     user: String,
+/// }
+///
+/// redis_model!(Session, key = id: String);
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::default().await?;
+///
+///     let session = Session { id: "session:1".to_string(), user: "john".to_string() };
+///     client.set(&session).await?;
+///
+///     let session: Option<Session> = client.get("session:1").await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! redis_model {
+    ($model:ty, key = $field:ident: $key:ty) => {
+        impl $crate::redis::RedisModel for $model {
+            type Key = $key;
+            type Value = String;
+
+            fn key(&self) -> $crate::redis::Result<Self::Key> {
+                Ok(::std::clone::Clone::clone(&self.$field))
+            }
+
+            fn key_ref(&self) -> &Self::Key {
+                &self.$field
+            }
+
+            fn value_ref(&self) -> &Self::Value {
+                static PLACEHOLDER: String = String::new();
+                &PLACEHOLDER
+            }
+        }
+    };
+}
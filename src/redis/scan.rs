@@ -0,0 +1,156 @@
+//! A module for paginating Redis keys using the `SCAN` command.
+//!
+//! This module provides the `PagableRedisScan` struct, which walks the Redis
+//! keyspace with a `SCAN` cursor and exposes the keys page by page through the
+//! `Pagable` trait, the same interface used by the Scylla stream pagination.
+//!
+//! Because the `COUNT` hint of `SCAN` is only advisory, a single `SCAN` call may
+//! return fewer or more keys than requested. `PagableRedisScan` keeps scanning
+//! until it has collected `per_page` keys or the cursor is exhausted, and buffers
+//! any surplus keys for the following page.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use grapple_db::redis::Client;
+//! use grapple_db::Pagable;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::default().await?;
+//!
+//!     // Iterate over all keys starting with "user:" by 10 keys per page
+//!     let mut pages = client.scan_pages(Some("user:*"), 10).await?;
+//!
+//!     while let Some(keys) = pages.next_page().await {
+//!         for key in keys {
+//!             println!("Key: {}", key);
+//!         }
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use deadpool_redis::{redis::cmd, Connection};
+
+use crate::Pagable;
+
+/// A paginated iterator over Redis keys backed by a `SCAN` cursor.
+///
+/// # Fields
+///
+/// - `connection`: The connection used to issue `SCAN` commands.
+/// - `pattern`: Optional `MATCH` pattern applied to the scan.
+/// - `per_page`: The number of keys to retrieve per page.
+/// - `cursor`: The current `SCAN` cursor.
+/// - `finished`: Whether the cursor has completed a full iteration.
+/// - `buffer`: Keys returned by `SCAN` that did not fit into the previous page.
+/// - `page_items`: A vector that holds the keys of the current page.
+pub struct PagableRedisScan {
+    connection: Connection,
+    pattern: Option<String>,
+    per_page: usize,
+    cursor: u64,
+    finished: bool,
+    buffer: VecDeque<String>,
+    page_items: Vec<String>,
+}
+
+impl PagableRedisScan {
+    /// Creates a new instance of `PagableRedisScan`.
+    ///
+    /// # Parameters
+    ///
+    /// - `connection`: The connection used to issue `SCAN` commands.
+    /// - `pattern`: Optional `MATCH` pattern, e.g. `"user:*"`. `None` scans all keys.
+    /// - `per_page`: The number of keys to retrieve per page.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `PagableRedisScan`.
+    pub fn new(connection: Connection, pattern: Option<&str>, per_page: usize) -> Self {
+        Self {
+            connection,
+            pattern: pattern.map(|p| p.to_string()),
+            per_page,
+            cursor: 0,
+            finished: false,
+            buffer: VecDeque::new(),
+            page_items: Vec::with_capacity(per_page),
+        }
+    }
+
+    /// Issues a single `SCAN` call and appends returned keys to the buffer.
+    ///
+    /// Returns `false` if the scan failed, in which case the iteration is
+    /// marked as finished.
+    async fn scan_once(&mut self) -> bool {
+        let mut command = cmd("SCAN");
+        command.arg(self.cursor);
+
+        if let Some(pattern) = &self.pattern {
+            command.arg("MATCH").arg(pattern);
+        }
+
+        command.arg("COUNT").arg(self.per_page.max(1));
+
+        match command
+            .query_async::<(u64, Vec<String>)>(&mut self.connection)
+            .await
+        {
+            Ok((cursor, keys)) => {
+                self.cursor = cursor;
+                self.finished = cursor == 0;
+                self.buffer.extend(keys);
+                true
+            }
+            Err(_) => {
+                self.finished = true;
+                false
+            }
+        }
+    }
+
+    /// Fills the buffer until it holds at least `per_page` keys or the cursor completes.
+    async fn fill_buffer(&mut self) {
+        while self.buffer.len() < self.per_page && !self.finished {
+            if !self.scan_once().await {
+                break;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Pagable<String> for PagableRedisScan {
+    async fn next_page(&mut self) -> Option<&[String]> {
+        self.page_items.clear();
+        self.fill_buffer().await;
+
+        let available = self.per_page.min(self.buffer.len());
+        self.page_items.extend(self.buffer.drain(..available));
+
+        if available == 0 {
+            None
+        } else {
+            Some(self.page_items())
+        }
+    }
+
+    async fn skip_page(&mut self) {
+        self.page_items.clear();
+        self.fill_buffer().await;
+
+        let available = self.per_page.min(self.buffer.len());
+        self.buffer.drain(..available);
+    }
+
+    #[inline]
+    fn page_items(&self) -> &[String] {
+        &self.page_items
+    }
+}
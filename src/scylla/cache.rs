@@ -0,0 +1,95 @@
+//! A module for caching results of count queries.
+//!
+//! This module provides the `CountCache` struct, which memoizes the results of
+//! count queries for a short time window. It is used by `Client::count_cached` when
+//! the cache is enabled with `Client::with_count_cache`, so that dashboards polling the
+//! same count every few seconds don't stream the whole result set each time.
+//!
+//! The cache is shared between clones of the client, is thread-safe and holds at
+//! most `COUNT_CACHE_CAPACITY` entries.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Maximum number of entries held by the count cache.
+pub(crate) const COUNT_CACHE_CAPACITY: usize = 1000;
+
+/// A thread-safe, bounded cache of count results keyed by the query string and cache key.
+///
+/// # Fields
+///
+/// - `ttl`: How long a cached count stays valid.
+/// - `entries`: Cached counts together with the time they were stored.
+/// - `scans`: The number of counts that were not served from the cache.
+#[derive(Debug)]
+pub(crate) struct CountCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, usize)>>,
+    scans: AtomicUsize,
+}
+
+impl CountCache {
+    /// Creates a new instance of `CountCache`.
+    ///
+    /// # Parameters
+    ///
+    /// - `ttl`: How long a cached count stays valid.
+    ///
+    /// # Returns
+    ///
+    /// A new, empty instance of `CountCache`.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            scans: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the cached count for the query if it is still valid.
+    ///
+    /// A miss is counted as a scan, since the caller has to run the query.
+    pub(crate) fn get(&self, query: &str) -> Option<usize> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        match entries.get(query) {
+            Some((stored, count)) if stored.elapsed() < self.ttl => Some(*count),
+            _ => {
+                self.scans.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Stores the count for the query.
+    ///
+    /// When the cache is full, expired entries are removed first, then the
+    /// oldest entry is evicted if there is still no room.
+    pub(crate) fn insert(&self, query: String, count: usize) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        if entries.len() >= COUNT_CACHE_CAPACITY && !entries.contains_key(&query) {
+            entries.retain(|_, (stored, _)| stored.elapsed() < self.ttl);
+        }
+
+        if entries.len() >= COUNT_CACHE_CAPACITY && !entries.contains_key(&query) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (stored, _))| *stored)
+                .map(|(key, _)| key.clone());
+
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(query, (Instant::now(), count));
+    }
+
+    /// Returns the number of counts that were not served from the cache.
+    pub(crate) fn scans(&self) -> usize {
+        self.scans.load(Ordering::Relaxed)
+    }
+}
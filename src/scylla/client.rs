@@ -4,28 +4,44 @@
 //! using the Charybdis ORM and Scylla driver. It offers connection management,
 //! CRUD operations, batch processing, streaming, and keyspace management.
 
-use std::{fmt::Debug, path::Path, sync::Arc};
-
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::{fmt::Debug, future::Future, io, path::Path, sync::Arc, time::Duration};
+
+use super::cache::CountCache;
 use super::migrate::MigrationBuilder;
 use super::model::Model;
-use super::operations::{CharybdisModelBatch, Delete, Insert, ModelBatch, Update};
+use super::operations::{CharybdisModelBatch, Delete, Find, Insert, ModelBatch, Update};
 use super::query::{CharybdisQuery, ModelMutation, ModelRow, ModelStream, QueryExecutor};
-use super::stream::CharybdisModelStream;
-use super::Result;
-use super::{ConnectionParams, CrudParams};
+use super::stream::{CharybdisModelStream, PagableCharybdisStream};
+use super::{ConnectionParams, CrudParams, DedupStrategy};
+use super::{Error, Result};
 
 use charybdis::query::OptionalModelRow;
+use charybdis::scylla::deserialize::row::DeserializeRow;
 use charybdis::scylla::response::query_result::QueryResult;
 use charybdis::scylla::serialize::row::SerializeRow;
+use charybdis::scylla::statement::batch::{Batch, BatchType};
+use charybdis::scylla::statement::unprepared::Statement;
+use charybdis::scylla::statement::Consistency;
+use charybdis::scylla::value::Row;
 use futures::future::join_all;
-use futures::StreamExt;
-use tracing::debug;
+use futures::{Stream, StreamExt, TryStreamExt};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
 
 pub use scylla::client::caching_session::*;
 pub use scylla::client::session::*;
 pub use scylla::client::session_builder::*;
 pub use scylla::client::*;
 
+/// Name of the table tracking applied migrations, created in the migrated keyspace
+const MIGRATIONS_TABLE: &str = "grapple_db_migrations";
+
 /// High-level ScyllaDB client that provides an abstraction layer over the Scylla driver
 ///
 /// The `Client` struct encapsulates a cached session to ScyllaDB and optional CRUD parameters
@@ -51,8 +67,28 @@ pub use scylla::client::*;
 pub struct Client {
     /// Thread-safe reference to the cached ScyllaDB session
     session: Arc<CachingSession>,
+    /// Driver session shared with the cache wrapper, used to rebuild the cache with a new
+    /// capacity. Not available for clients created from an existing cached session
+    shared_session: Option<Arc<Session>>,
     /// Optional CRUD parameters for customizing query execution
     crud_params: Option<CrudParams>,
+    /// Optional cache of count results shared between clones of the client
+    count_cache: Option<Arc<CountCache>>,
+    /// Keyspace the client is expected to work in, used to enrich errors
+    keyspace: Option<String>,
+    /// Optional consistency level for keyspace and table management statements
+    schema_consistency: Option<Consistency>,
+}
+
+/// A node of the cluster as known by the driver, returned by `Client::nodes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// Address the driver uses to connect to the node
+    pub address: SocketAddr,
+    /// Datacenter of the node, if reported by the cluster
+    pub datacenter: Option<String>,
+    /// Whether the driver currently considers the node up
+    pub is_up: bool,
 }
 
 // ================================================================================================
@@ -114,7 +150,11 @@ impl Client {
     pub fn from_session(session: &Arc<CachingSession>) -> Result<Self> {
         Ok(Self {
             session: session.clone(),
+            shared_session: None,
             crud_params: None,
+            count_cache: None,
+            keyspace: session.get_session().get_keyspace().map(|k| k.to_string()),
+            schema_consistency: None,
         })
     }
 
@@ -130,10 +170,14 @@ impl Client {
     /// # Connection Process
     ///
     /// 1. Creates a cached session using the connection parameters
-    /// 2. Optionally creates or recreates the specified keyspace
+    /// 2. Optionally creates or recreates the specified keyspace (or fails if it is
+    ///    missing and `create_missing_keyspace` is disabled)
     /// 3. Sets the keyspace as the default for the session
     /// 4. Executes any initialization files specified in the parameters
     /// 5. Runs database migrations if enabled
+    /// 6. Prepares the statements listed in `prepare_statements`
+    ///
+    /// If `init_files_before_keyspace` is set, step 4 runs before step 2 instead.
     ///
     /// # Returns
     ///
@@ -164,26 +208,60 @@ impl Client {
     pub async fn connect(con_params: &ConnectionParams) -> Result<Self> {
         debug!("Connecting to {}", con_params.uri);
 
-        let session = con_params.caching().await?;
+        let shared_session = Arc::new(con_params.build().await?);
         let client = Self {
-            session: Arc::new(session),
+            session: Arc::new(Self::caching_session(
+                &shared_session,
+                con_params.caching_capacity,
+            )),
+            shared_session: Some(shared_session),
             crud_params: None,
+            count_cache: None,
+            keyspace: con_params
+                .use_keyspace
+                .clone()
+                .filter(|_| con_params.set_active_keyspace),
+            schema_consistency: con_params.schema_consistency,
         };
 
+        // Execute initialization files before keyspace setup if requested
+        if con_params.init_files_before_keyspace {
+            client.execute_files(&con_params.init_files).await?;
+        }
+
         // Handle keyspace setup if specified
         if let Some(keyspace) = &con_params.use_keyspace {
             if con_params.recreate_keyspace {
                 client.recreate_keyspace(keyspace).await?;
-            } else {
+            } else if con_params.create_missing_keyspace {
                 client.create_keyspace(keyspace).await?;
+            } else {
+                let exists = client.keyspaces().await?.iter().any(|k| {
+                    if con_params.keyspace_case_sensitive {
+                        k == keyspace
+                    } else {
+                        k.eq_ignore_ascii_case(keyspace)
+                    }
+                });
+
+                if !exists {
+                    return Err(Error::KeyspaceNotFound(keyspace.clone()));
+                }
             }
 
-            client.use_keyspace(keyspace).await?;
+            if con_params.set_active_keyspace {
+                client.use_keyspace(keyspace).await?;
+            }
         }
 
         // Execute initialization files
-        for filename in &con_params.init_files {
-            client.execute_file(filename).await?;
+        if !con_params.init_files_before_keyspace {
+            client.execute_files(&con_params.init_files).await?;
+        }
+
+        // Run the connection-init callback
+        if let Some(on_connect) = &con_params.on_connect {
+            on_connect(client.session.get_session()).await?;
         }
 
         // Run migrations if enabled
@@ -191,6 +269,20 @@ impl Client {
             Self::migrate(client.session.get_session(), &con_params.use_keyspace).await?;
         }
 
+        // Prepare statements ahead of their first execution
+        for query in &con_params.prepare_statements {
+            debug!("Preparing statement: {}", query);
+
+            client
+                .session
+                .add_prepared_statement(&Statement::new(query.as_str()))
+                .await
+                .map_err(|error| Error::PrepareStatement {
+                    query: query.clone(),
+                    error: Box::new(error.into()),
+                })?;
+        }
+
         Ok(client)
     }
 }
@@ -202,8 +294,8 @@ impl Client {
     /// Sets CRUD parameters for customizing query execution
     ///
     /// CRUD parameters allow you to specify default consistency levels, timeouts,
-    /// and timestamps that will be applied to all database operations performed
-    /// by this client instance.
+    /// timestamps and a retry policy that will be applied to all database operations
+    /// performed by this client instance.
     ///
     /// # Arguments
     ///
@@ -228,6 +320,8 @@ impl Client {
     ///             consistency: Consistency::Quorum,
     ///             timeout: Some(Duration::from_secs(30)),
     ///             timestamp: None,
+    ///             serial_consistency: None,
+    ///             retry: None,
     ///         });
     ///
     ///     // Do something with client
@@ -239,6 +333,157 @@ impl Client {
         _ = self.crud_params.insert(params.into());
         self
     }
+
+    /// Enables a short-lived cache for the results of `count_cached`
+    ///
+    /// Counts are memoized for `ttl` and served from the cache when the same query
+    /// is counted again with the same cache key within this window. The cache is
+    /// shared between clones of the client, is thread-safe and bounded in size.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - How long a cached count stays valid
+    ///
+    /// # Returns
+    ///
+    /// The client instance with the count cache enabled (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .with_count_cache(Duration::from_secs(5));
+    ///
+    ///     // Do something with client
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_count_cache(mut self, ttl: Duration) -> Self {
+        _ = self.count_cache.insert(Arc::new(CountCache::new(ttl)));
+        self
+    }
+
+    /// Sets the keyspace the client is expected to work in
+    ///
+    /// The keyspace is only recorded, not activated (use `use_keyspace` for that).
+    /// When a raw query or a retried operation fails because no keyspace is set on
+    /// the session, the error is wrapped into `Error::MissingKeyspace` mentioning the
+    /// expected keyspace, which is clearer than the driver error. Clients created with
+    /// `connect` record the keyspace from `ConnectionParams::use_keyspace` automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the expected keyspace
+    ///
+    /// # Returns
+    ///
+    /// The client instance with the recorded keyspace (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{CachingSession, Client};
+    /// use std::sync::Arc;
+    ///
+    /// fn create_client(session: Arc<CachingSession>) -> Result<Client, Box<dyn std::error::Error>> {
+    ///     Ok(Client::from_session(&session)?.with_default_keyspace("my_keyspace"))
+    /// }
+    /// ```
+    pub fn with_default_keyspace(mut self, name: &str) -> Self {
+        _ = self.keyspace.insert(name.to_string());
+        self
+    }
+
+    /// Sets the consistency level for schema changes
+    ///
+    /// Keyspace and table management statements (`create_keyspace`, `drop_keyspace`,
+    /// `recreate_keyspace`, `drop_table`, `clone_schema`) are executed with this
+    /// consistency. Clients created with `connect` take it from
+    /// `ConnectionParams::schema_consistency` automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `consistency` - The consistency level for schema changes
+    ///
+    /// # Returns
+    ///
+    /// The client instance with the schema consistency set (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::Consistency;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .with_schema_consistency(Consistency::EachQuorum);
+    ///
+    ///     client.create_keyspace("my_keyspace").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_schema_consistency(mut self, consistency: Consistency) -> Self {
+        _ = self.schema_consistency.insert(consistency);
+        self
+    }
+
+    /// Rebuilds the prepared statement cache with a new capacity
+    ///
+    /// The driver session, with its connections and active keyspace, is kept; only the
+    /// cache wrapper around it is replaced. The new cache starts empty, so statements are
+    /// prepared again on their first execution. Clones of the client made before the
+    /// resize keep using the previous cache.
+    ///
+    /// Only clients created with `connect` can resize their cache. Clients created with
+    /// `from_session` don't share the driver session of their cached session.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of prepared statements to cache
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success, or `Error::StatementCacheNotResizable` if the client
+    /// was created from an existing cached session.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut client = Client::default().await?;
+    ///
+    ///     // Cache more statements as the query patterns grow
+    ///     client.resize_statement_cache(5000)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn resize_statement_cache(&mut self, capacity: usize) -> Result<()> {
+        let shared_session = self
+            .shared_session
+            .as_ref()
+            .ok_or(Error::StatementCacheNotResizable)?;
+
+        debug!("Resizing the statement cache to {}", capacity);
+
+        self.session = Arc::new(Self::caching_session(shared_session, capacity));
+
+        Ok(())
+    }
 }
 
 // ================================================================================================
@@ -272,6 +517,77 @@ impl Client {
     pub fn session(&self) -> Arc<CachingSession> {
         self.session.clone()
     }
+
+    /// Returns a reference to the driver session wrapped by the cached session
+    ///
+    /// Some driver APIs, such as the cluster state and metrics, are only
+    /// available on the `Session` itself. Queries executed through it bypass
+    /// the prepared statement cache.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the underlying `Session`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let nodes = client.raw_session().get_cluster_state().get_nodes_info().len();
+    ///     println!("Known nodes: {}", nodes);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn raw_session(&self) -> &Session {
+        self.session.get_session()
+    }
+
+    /// Returns the nodes of the cluster known by the driver
+    ///
+    /// The nodes are read from the driver's current view of the cluster, which is
+    /// refreshed from `system.local` and `system.peers` as the topology changes, so
+    /// no query is issued. Useful for operational dashboards.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the address, datacenter and up/down status of every known node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     for node in client.nodes()? {
+    ///         println!("{} ({:?}): up = {}", node.address, node.datacenter, node.is_up);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn nodes(&self) -> Result<Vec<NodeInfo>> {
+        let nodes = self
+            .raw_session()
+            .get_cluster_state()
+            .get_nodes_info()
+            .iter()
+            .map(|node| NodeInfo {
+                address: node.address.into_inner(),
+                datacenter: node.datacenter.clone(),
+                is_up: !node.is_down(),
+            })
+            .collect();
+
+        Ok(nodes)
+    }
 }
 
 // ================================================================================================
@@ -407,6 +723,68 @@ impl Client {
         Ok(res)
     }
 
+    /// Retrieves the first entity matching a multi-row query, if any.
+    ///
+    /// This is a single-call "first match" lookup for queries returning a stream, such
+    /// as secondary-index finders. It behaves exactly like `first`, which sets the page
+    /// size of the query to 1 since a `LIMIT` clause can't be added to a generated
+    /// Charybdis query. The query is automatically enhanced with any CRUD parameters
+    /// configured for this client instance.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being retrieved
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A Charybdis query configured to return a stream of results
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<E>`, where `Some(E)` is the first matching entity,
+    /// or `None` if no record matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #       global_secondary_indexes = [email],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// #     email: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let user: Option<User> = client
+    ///         .first_optional(User::find_by_email("john@example.com".to_string()))
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn first_optional<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+    ) -> Result<Option<E>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+    {
+        self.first(query).await
+    }
+
     /// Retrieves multiple entities from the database based on the provided queries.
     ///
     /// Use this method when you need to retrieve entities by their IDs; this method is significantly faster
@@ -491,19 +869,17 @@ impl Client {
         Ok(result)
     }
 
-    /// Counts the total number of entities that match the given query
-    ///
-    /// This method executes a streaming query and counts all the results without loading
-    /// them into memory. It's an efficient way to get the count of entities that match
-    /// specific criteria without the overhead of retrieving and deserializing all the data.
+    /// Retrieves the first entity returned by a streaming query
     ///
-    /// The method internally uses the streaming functionality to iterate through all
-    /// matching records and returns the total count.
+    /// This method executes a streaming query and pulls only the first item, which is
+    /// convenient for secondary-index lookups that are expected to return at most one row.
+    /// The page size of the query is set to 1, as a `LIMIT` clause can't be added to a
+    /// generated Charybdis query, and the rest of the stream is dropped.
     ///
     /// # Type Parameters
     ///
     /// * `Val` - The type of values being serialized for the query
-    /// * `E` - The entity/model type being counted
+    /// * `E` - The entity/model type being retrieved
     ///
     /// # Arguments
     ///
@@ -511,15 +887,8 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// A `Result` containing the total count of entities matching the query, or an error
-    /// if the query execution fails.
-    ///
-    /// # Performance Notes
-    ///
-    /// This method streams through all matching records to count them, which means:
-    /// - Memory usage is minimal as records are not stored
-    /// - For large result sets, this may take time as it processes all records
-    /// - Consider using database-native COUNT queries for better performance on large datasets
+    /// A `Result` containing `Some(E)` with the first entity, or `None` if the query
+    /// returned no rows.
     ///
     /// # Examples
     ///
@@ -531,53 +900,56 @@ impl Client {
     /// #       table_name = users,
     /// #       partition_keys = [id],
     /// #       clustering_keys = [],
-    /// #       global_secondary_indexes = [status],
+    /// #       global_secondary_indexes = [email],
     /// #   )]
     /// # #[derive(Debug, Default)]
     /// # struct User {
     /// #     id: String,
-    /// #     status: String,
+    /// #     email: String,
     /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     // Count all active users
-    ///     let count = client.count(User::find_by_status("active".to_string())).await?;
-    ///     println!("Total active users: {}", count);
+    ///     let user = client
+    ///         .first(User::find_by_email("john@example.com".to_string()))
+    ///         .await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn count<'a, Val, E>(
+    pub async fn first<'a, Val, E>(
         &self,
         query: CharybdisQuery<'a, Val, E, ModelStream>,
-    ) -> Result<usize>
+    ) -> Result<Option<E>>
     where
-        Val: SerializeRow + Sync + Send + Debug,
+        Val: SerializeRow + Sync + Send,
         E: Model + Sync + Send + 'static,
     {
-        Ok(self.stream(query).await?.count().await)
+        let mut stream = self.stream(query.page_size(1)).await?;
+
+        Ok(stream.next().await.transpose()?)
     }
 
-    /// Updates a single entity in the database
+    /// Re-fetches an entity from the database by its primary key
     ///
-    /// This method takes an entity that implements the `Update` trait and
-    /// generates an update query automatically. The entity's `update()` method
-    /// is called to create the appropriate Charybdis query.
+    /// This method extracts the primary key of the given entity and issues the
+    /// corresponding find query, so callers don't have to re-state the key to get
+    /// the fresh state of a row. The query is automatically enhanced with any CRUD
+    /// parameters configured for this client instance.
     ///
     /// # Type Parameters
     ///
-    /// * `E` - The entity/model type being updated
+    /// * `E` - The entity/model type being reloaded
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity instance to update
+    /// * `entity` - The entity whose row should be fetched again
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the update operation.
+    /// A `Result` containing the fresh entity, or an error if the row no longer exists.
     ///
     /// # Examples
     ///
@@ -600,63 +972,42 @@ impl Client {
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     /// Get user somehow
-    ///     let mut user = User::default();
-    ///     user.name = "New Name".to_string();
-    ///     client.update(&user).await?;
+    ///     let user = User { id: "1".to_string(), name: "John".to_string() };
+    ///     client.insert(&user).await?;
+    ///
+    ///     // ... the row may be changed elsewhere ...
+    ///
+    ///     let user = client.reload(&user).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn update<E>(&self, entity: &E) -> Result<()>
+    pub async fn reload<E>(&self, entity: &E) -> Result<E>
     where
-        E: Model + Update + Sync + Send + 'static,
+        E: Model + Sync + Send,
     {
-        self.update_query(entity.update()).await?;
-
-        Ok(())
+        self.get(entity.find_by_primary_key()).await
     }
 
-    /// Internal method for executing update queries
+    /// Checks whether a row with the given primary key exists
     ///
-    /// This method handles the actual execution of update queries with proper
-    /// parameter application and logging.
-    async fn update_query<'a, Val, E>(
-        &self,
-        query: CharybdisQuery<'a, Val, E, ModelMutation>,
-    ) -> Result<()>
-    where
-        Val: SerializeRow + Sync + Send,
-        E: Model + Sync + Send,
-    {
-        debug!("Update query: {}", query.query_string());
-
-        _ = self
-            .query_apply_params(query)
-            .execute(&self.session)
-            .await?;
-
-        Ok(())
-    }
-
-    /// Updates multiple entities in the database using batch operations
-    ///
-    /// This method efficiently updates a large number of entities by grouping
-    /// them into batches of the specified size. This reduces the number of
-    /// round trips to the database and improves performance.
+    /// Unlike `get_optional(...).is_some()`, this method doesn't transfer the columns
+    /// of the row. It issues the model's find-by-primary-key query with the selection
+    /// replaced by `COUNT(*)`, so only a single counter is returned. The query is
+    /// automatically enhanced with any CRUD parameters configured for this client instance.
     ///
     /// # Type Parameters
     ///
-    /// * `E` - The entity/model type being updated
+    /// * `E` - The entity/model type being checked
     ///
     /// # Arguments
     ///
-    /// * `iter` - A slice of entities to update
-    /// * `chunk_size` - The number of entities to include in each batch
+    /// * `primary_key` - The primary key values of the row, in the order of the model's
+    ///   partition and clustering keys
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the batch update operation.
+    /// A `Result` containing `true` if the row exists, or `false` otherwise.
     ///
     /// # Examples
     ///
@@ -678,186 +1029,214 @@ impl Client {
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     let users: Vec<User> = vec![/* ... users to update ... */];
-    ///
-    ///     client.update_many(&users, 1000).await?;
+    ///     if client.exists::<User>(("1".to_string(),)).await? {
+    ///         println!("User exists");
+    ///     }
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn update_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    pub async fn exists<E>(&self, primary_key: E::PrimaryKey) -> Result<bool>
     where
-        E: ModelBatch<'a> + Sync + Send + 'a,
+        E: Model + Sync + Send,
     {
-        self.batch_apply_params(E::batch())
-            .chunked_update(&self.session, iter, chunk_size)
-            .await?;
+        let query = Self::count_query(E::FIND_BY_PRIMARY_KEY_QUERY);
 
-        Ok(())
+        debug!("Exists query: {}", query);
+
+        let count = self
+            .execute(&query, primary_key)
+            .await?
+            .into_rows_result()?
+            .rows::<(i64,)>()?
+            .next()
+            .transpose()?
+            .map_or(0, |(count,)| count);
+
+        Ok(count > 0)
     }
 
-    /// Inserts a single entity into the database
+    /// Counts the total number of entities that match the given query
     ///
-    /// This method takes an entity that implements the `Insert` trait and
-    /// generates an insert query automatically. The entity's `insert()` method
-    /// is called to create the appropriate Charybdis query.
+    /// This method executes a streaming query and counts all the results without loading
+    /// them into memory. It's an efficient way to get the count of entities that match
+    /// specific criteria without the overhead of retrieving and deserializing all the data.
+    ///
+    /// The method internally uses the streaming functionality to iterate through all
+    /// matching records and returns the total count.
     ///
     /// # Type Parameters
     ///
-    /// * `E` - The entity/model type being inserted
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being counted
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity instance to insert
+    /// * `query` - A Charybdis query configured to return a stream of results
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the insert operation.
+    /// A `Result` containing the total count of entities matching the query, or an error
+    /// if the query execution fails.
+    ///
+    /// # Performance Notes
+    ///
+    /// This method streams through all matching records to count them, which means:
+    /// - Memory usage is minimal as records are not stored
+    /// - For large result sets, this may take time as it processes all records
+    /// - Consider using database-native COUNT queries for better performance on large datasets
+    /// - Repeated counts can be served from a cache with `count_cached`
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
-    /// use grapple_db::scylla::operations::New;
     ///
     /// // Assuming you have a `User` model defined with `Charybdis`
     /// # #[grapple_db::scylla::macros::charybdis_model(
     /// #       table_name = users,
-    /// #       partition_keys = [name],
+    /// #       partition_keys = [id],
     /// #       clustering_keys = [],
+    /// #       global_secondary_indexes = [status],
     /// #   )]
     /// # #[derive(Debug, Default)]
     /// # struct User {
-    /// #     name: String,
-    /// # }
-    /// #
-    /// # impl User{
-    /// #    pub fn new(name: &str) -> Self {
-    /// #        Self { name: name.to_string() }
-    /// #    }
+    /// #     id: String,
+    /// #     status: String,
     /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     let user = User::new("John Doe");
-    ///     client.insert(&user).await?;
+    ///     // Count all active users
+    ///     let count = client.count(User::find_by_status("active".to_string())).await?;
+    ///     println!("Total active users: {}", count);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn insert<E>(&self, entity: &E) -> Result<()>
-    where
-        E: Model + Insert + Sync + Send + 'static,
-    {
-        self.insert_query(entity.insert()).await?;
-
-        Ok(())
-    }
-
-    /// Internal method for executing insert queries
-    ///
-    /// This method handles the actual execution of insert queries with proper
-    /// parameter application and logging.
-    async fn insert_query<'a, Val, E>(
+    pub async fn count<'a, Val, E>(
         &self,
-        query: CharybdisQuery<'a, Val, E, ModelMutation>,
-    ) -> Result<()>
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+    ) -> Result<usize>
     where
-        Val: SerializeRow + Sync + Send,
-        E: Model + Sync + Send,
+        Val: SerializeRow + Sync + Send + Debug,
+        E: Model + Sync + Send + 'static,
     {
-        debug!("Insert query: {}", query.query_string());
-
-        _ = self
-            .query_apply_params(query)
-            .execute(&self.session)
-            .await?;
-
-        Ok(())
+        Ok(self.stream(query).await?.count().await)
     }
 
-    /// Inserts multiple entities into the database using batch operations
+    /// Counts the entities matching a query, serving repeated counts from the count cache
     ///
-    /// This method efficiently inserts a large number of entities by grouping
-    /// them into batches of the specified size. This is much more efficient
-    /// than inserting entities one by one.
+    /// This method works like `count`, but when the cache is enabled with `with_count_cache`,
+    /// the result is memoized under the query string and `key`. The bound values of a
+    /// Charybdis query are not accessible, so `key` must identify them: counting the same
+    /// finder with different values and the same key would return the cached count of the
+    /// first values. Without the cache, this method is equivalent to `count`.
     ///
     /// # Type Parameters
     ///
-    /// * `E` - The entity/model type being inserted
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being counted
     ///
     /// # Arguments
     ///
-    /// * `iter` - A slice of entities to insert
-    /// * `chunk_size` - The number of entities to include in each batch
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `key` - A key identifying the bound values of the query, e.g. the values themselves
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the batch insert operation.
+    /// A `Result` containing the total count of entities matching the query.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
+    /// use std::time::Duration;
     ///
     /// // Assuming you have a `User` model defined with `Charybdis`
     /// # #[grapple_db::scylla::macros::charybdis_model(
     /// #       table_name = users,
     /// #       partition_keys = [id],
     /// #       clustering_keys = [],
+    /// #       global_secondary_indexes = [status],
     /// #   )]
     /// # #[derive(Debug, Default)]
     /// # struct User {
     /// #     id: String,
+    /// #     status: String,
     /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::default().await?;
+    ///     let client = Client::default()
+    ///         .await?
+    ///         .with_count_cache(Duration::from_secs(5));
     ///     
-    ///     let users: Vec<User> = vec![/* ... users to insert ... */];
-    ///     client.insert_many(&users, 1000).await?;
+    ///     let status = "active".to_string();
+    ///     let count = client
+    ///         .count_cached(User::find_by_status(status.clone()), &status)
+    ///         .await?;
+    ///     println!("Total active users: {}", count);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn insert_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    pub async fn count_cached<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        key: &str,
+    ) -> Result<usize>
     where
-        E: ModelBatch<'a> + Sync + Send + 'a,
+        Val: SerializeRow + Sync + Send + Debug,
+        E: Model + Sync + Send + 'static,
     {
-        self.batch_apply_params(E::batch())
-            .chunked_insert(&self.session, iter, chunk_size)
-            .await?;
+        let Some(cache) = &self.count_cache else {
+            return self.count(query).await;
+        };
 
-        Ok(())
+        let key = format!("{}\n{}", query.query_string(), key);
+
+        if let Some(count) = cache.get(&key) {
+            debug!("Count served from cache: {}", key);
+            return Ok(count);
+        }
+
+        let count = self.stream(query).await?.count().await;
+        cache.insert(key, count);
+
+        Ok(count)
     }
 
-    /// Deletes a single entity from the database
+    /// Counts the entities matching a query that requires `ALLOW FILTERING`
     ///
-    /// This method takes an entity that implements the `Delete` trait and
-    /// generates a delete query automatically. The entity's `delete()` method
-    /// is called to create the appropriate Charybdis query.
+    /// This method works like `count`, but takes a CQL `SELECT` query whose predicate
+    /// is not backed by a key or an index. `ALLOW FILTERING` is appended to the query
+    /// (unless it is already present), which makes full-table filtered counts possible
+    /// but explicit: every call emits a `warn!` log, as with `stream_filtered`.
+    /// Filtered counts are not cached.
     ///
     /// # Type Parameters
     ///
-    /// * `E` - The entity/model type being deleted
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being counted
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity instance to delete
+    /// * `query` - A CQL `SELECT` query returning rows of the model's table
+    /// * `values` - Values to bind to the query parameters
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the delete operation.
+    /// A `Result` containing the number of entities matching the query.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
-    /// // Assuming you have a User model defined
+    /// // Assuming you have a `User` model defined with `Charybdis`
     /// # #[grapple_db::scylla::macros::charybdis_model(
     /// #       table_name = users,
     /// #       partition_keys = [id],
@@ -866,140 +1245,157 @@ impl Client {
     /// # #[derive(Debug, Default)]
     /// # struct User {
     /// #     id: String,
+    /// #     age: i32,
     /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     /// Get user somehow
-    ///     let user = User::default();
-    ///     client.delete(&user).await?;
+    ///     let adults = client
+    ///         .count_filtered::<_, User>("SELECT * FROM users WHERE age > ?", (18,))
+    ///         .await?;
+    ///     println!("Adult users: {}", adults);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn delete<E>(&self, entity: &E) -> Result<()>
-    where
-        E: Model + Delete + Sync + Send + 'static,
-    {
-        self.delete_query(entity.delete()).await?;
-
-        Ok(())
-    }
-
-    /// Internal method for executing delete queries
-    ///
-    /// This method handles the actual execution of delete queries with proper
-    /// parameter application and logging.
-    async fn delete_query<'a, Val, E>(
-        &self,
-        query: CharybdisQuery<'a, Val, E, ModelMutation>,
-    ) -> Result<()>
+    pub async fn count_filtered<Val, E>(&self, query: &str, values: Val) -> Result<usize>
     where
         Val: SerializeRow + Sync + Send,
-        E: Model + Sync + Send,
+        E: Model + Sync + Send + 'static,
     {
-        debug!("Delete query: {}", query.query_string());
-
-        _ = self
-            .query_apply_params(query)
-            .execute(&self.session)
-            .await?;
+        let count = self
+            .stream_filtered::<Val, E>(query, values)
+            .await?
+            .count()
+            .await;
 
-        Ok(())
+        Ok(count)
     }
 
-    /// Deletes multiple entities from the database using batch operations
-    ///
-    /// This method efficiently deletes a large number of entities by grouping
-    /// them into batches of the specified size. This reduces the number of
-    /// round trips to the database and improves performance.
-    ///
-    /// # Type Parameters
+    /// Counts the rows matching a query with a database-native `COUNT(*)`
     ///
-    /// * `E` - The entity/model type being deleted
+    /// Unlike `count`, the rows are counted by the database and only a single counter is
+    /// transferred. The selection of the query is replaced by `COUNT(*)`, and the statement
+    /// is built with the CRUD parameters configured for this client instance, so the count
+    /// runs at the configured consistency and timeout. Counts are idempotent, so they are
+    /// retried according to the client's retry policy.
     ///
     /// # Arguments
     ///
-    /// * `iter` - A slice of entities to delete
-    /// * `chunk_size` - The number of entities to include in each batch
+    /// * `query` - A CQL `SELECT` query, e.g. `SELECT * FROM users WHERE name = ?`
+    /// * `values` - Values to bind to the query parameters
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the batch delete operation.
+    /// A `Result` containing the number of rows matching the query.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
-    /// // Assuming you have a `User` model defined with `Charybdis`
-    /// # #[grapple_db::scylla::macros::charybdis_model(
-    /// #       table_name = users,
-    /// #       partition_keys = [id],
-    /// #       clustering_keys = [],
-    /// #   )]
-    /// # #[derive(Debug, Default)]
-    /// # struct User {
-    /// #     id: String,
-    /// # }
-    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     let users: Vec<User> = vec![/* ... users to delete ... */];
-    ///     client.delete_many(&users, 1000).await?;
+    ///     let count = client
+    ///         .count_native("SELECT * FROM users WHERE name = ?", ("John",))
+    ///         .await?;
+    ///     println!("Users named John: {}", count);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn delete_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
-    where
-        E: ModelBatch<'a> + Sync + Send + 'a,
-    {
-        self.batch_apply_params(E::batch())
-            .chunked_delete(&self.session, iter, chunk_size)
-            .await?;
+    pub async fn count_native(&self, query: &str, values: impl SerializeRow) -> Result<usize> {
+        let statement = Self::count_statement(query, self.crud_params.as_ref());
+        self.count_with_statement(&statement, values).await
+    }
 
-        Ok(())
+    /// Counts the rows matching a query with a database-native `COUNT(*)` and CRUD parameters
+    ///
+    /// This method works like `count_native`, but applies the given CRUD parameters
+    /// instead of the client's ones, allowing one-off overrides of the consistency,
+    /// serial consistency, timeout and timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A CQL `SELECT` query, e.g. `SELECT * FROM users WHERE name = ?`
+    /// * `values` - Values to bind to the query parameters
+    /// * `params` - CRUD parameters to apply to the statement
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of rows matching the query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, CrudParams};
+    /// use grapple_db::scylla::statement::Consistency;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let params = CrudParams {
+    ///         consistency: Consistency::All,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let count = client
+    ///         .count_native_with("SELECT * FROM users WHERE name = ?", ("John",), &params)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn count_native_with(
+        &self,
+        query: &str,
+        values: impl SerializeRow,
+        params: &CrudParams,
+    ) -> Result<usize> {
+        let statement = Self::count_statement(query, Some(params));
+        self.count_with_statement(&statement, values).await
     }
 
-    /// Creates a stream for efficiently processing large result sets
+    /// Counts the rows of several partitions concurrently and sums the counts
     ///
-    /// This method executes a query that returns a stream of results, which is
-    /// useful for processing large datasets without loading everything into memory
-    /// at once. The stream can be used with pagination or consumed incrementally.
+    /// Counting across an `IN` list of partitions with `count` streams every row through
+    /// a single query. This method instead issues the model's find-by-partition-key query
+    /// with the selection replaced by `COUNT(*)` once per partition, running up to
+    /// `concurrency` counts at a time. The statement is built with the CRUD parameters
+    /// configured for this client instance. The first failing count aborts the operation.
     ///
     /// # Type Parameters
     ///
-    /// * `Val` - The type of values being serialized for the query
-    /// * `E` - The entity/model type being streamed
+    /// * `E` - The entity/model type being counted
+    /// * `K` - The partition key values, in the order of the model's partition keys
     ///
     /// # Arguments
     ///
-    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `keys` - The partition keys of the partitions to count
+    /// * `concurrency` - The maximum number of counts running at once; `0` is treated as `1`
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `CharybdisModelStream` for processing results.
+    /// A `Result` containing the total number of rows in the given partitions.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
-    /// use futures::StreamExt;
-    /// use grapple_db::scylla::operations::Find;
     ///
-    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// // Assuming you have a `Post` model defined with `Charybdis`
     /// # #[grapple_db::scylla::macros::charybdis_model(
-    /// #       table_name = users,
-    /// #       partition_keys = [id],
-    /// #       clustering_keys = [],
+    /// #       table_name = posts,
+    /// #       partition_keys = [author],
+    /// #       clustering_keys = [id],
     /// #   )]
     /// # #[derive(Debug, Default)]
-    /// # struct User {
+    /// # struct Post {
+    /// #     author: String,
     /// #     id: String,
     /// # }
     ///
@@ -1007,795 +1403,5447 @@ impl Client {
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     let mut stream = client.stream(User::find_all()).await?;
-    ///     while let Some(user) = stream.next().await {
-    ///          match user {
-    ///              Ok(user) => println!("User: {:?}", user),
-    ///              Err(e) => eprintln!("Error: {:?}", e),
-    ///          }
-    ///      }
+    ///     let authors = vec![("john".to_string(),), ("jane".to_string(),)];
+    ///     let count = client.count_partitions::<Post, _>(authors, 4).await?;
+    ///     println!("Posts by John and Jane: {}", count);
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn stream<'a, Val, E>(
-        &self,
-        query: CharybdisQuery<'a, Val, E, ModelStream>,
-    ) -> Result<CharybdisModelStream<E>>
+    pub async fn count_partitions<E, K>(&self, keys: Vec<K>, concurrency: usize) -> Result<usize>
     where
-        Val: SerializeRow + Sync + Send,
-        E: Model + Sync + Send + 'static,
+        E: Model + Sync + Send,
+        K: SerializeRow + Send,
     {
-        debug!("Stream query: {}", query.query_string());
+        let statement =
+            Self::count_statement(E::FIND_BY_PARTITION_KEY_QUERY, self.crud_params.as_ref());
 
-        let res = self
-            .query_apply_params(query)
-            .execute(&self.session)
-            .await?;
+        debug!("Count partitions query: {}", statement.contents);
 
-        Ok(res)
+        futures::stream::iter(keys)
+            .map(|key| self.count_with_statement(&statement, key))
+            .buffer_unordered(concurrency.max(1))
+            .try_fold(0, |total, count| async move { Ok(total + count) })
+            .await
     }
-}
 
-// ================================================================================================
-// Table Management
-// ================================================================================================
-impl Client {
-    /// Drops a table from the database if it exists
+    /// Updates a single entity in the database
     ///
-    /// This method executes a `DROP TABLE IF EXISTS` statement for the specified
-    /// table name. It's safe to call even if the table doesn't exist.
+    /// This method takes an entity that implements the `Update` trait and
+    /// generates an update query automatically. The entity's `update()` method
+    /// is called to create the appropriate Charybdis query.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being updated
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the table to drop
+    /// * `entity` - The entity instance to update
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the drop operation.
+    /// A `Result` indicating success or failure of the update operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// #     name: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     client.drop_table("old_users_table").await?;
+    ///     /// Get user somehow
+    ///     let mut user = User::default();
+    ///     user.name = "New Name".to_string();
+    ///     client.update(&user).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn drop_table(&self, name: &str) -> Result<()> {
-        let query = format!("DROP TABLE IF EXISTS {name};");
+    pub async fn update<E>(&self, entity: &E) -> Result<()>
+    where
+        E: Model + Update + Sync + Send + 'static,
+    {
+        self.with_retry(true, move || self.update_query(entity.update()))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method for executing update queries
+    ///
+    /// This method handles the actual execution of update queries with proper
+    /// parameter application and logging.
+    async fn update_query<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelMutation>,
+    ) -> Result<()>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send,
+    {
+        debug!("Update query: {}", query.query_string());
 
-        self.execute(&query, &[]).await?;
+        _ = self
+            .query_apply_params(query)
+            .execute(&self.session)
+            .await?;
 
         Ok(())
     }
-}
 
-// ================================================================================================
-// Keyspace Management
-// ================================================================================================
-impl Client {
-    /// Retrieves a list of all keyspaces in the ScyllaDB cluster
+    /// Updates multiple entities in the database using batch operations
     ///
-    /// This method queries the system schema to get a list of all available
-    /// keyspaces in the connected ScyllaDB cluster.
+    /// This method efficiently updates a large number of entities by grouping
+    /// them into batches of the specified size. This reduces the number of
+    /// round trips to the database and improves performance.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being updated
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - A slice of entities to update
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
     ///
     /// # Returns
     ///
-    /// A `Result` containing a vector of keyspace names or an error.
+    /// A `Result` indicating success or failure of the batch update operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     let keyspaces = client.keyspaces().await?;
-    ///     for keyspace in keyspaces {
-    ///         println!("Keyspace: {}", keyspace);
-    ///     }
+    ///     let users: Vec<User> = vec![/* ... users to update ... */];
+    ///
+    ///     client.update_many(&users, 1000).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn keyspaces(&self) -> Result<Vec<String>> {
-        let query = "SELECT keyspace_name FROM system_schema.keyspaces;";
-
-        let res = self.session.execute_unpaged(query, &[]).await?;
-
-        let keyspaces: Vec<String> = res
-            .into_rows_result()?
-            .rows::<(String,)>()?
-            .filter_map(|s| s.ok()) // Используем filter_map для извлечения значений
-            .map(|(keyspace_name,)| keyspace_name) // Извлекаем имя keyspace
-            .collect();
-
-        Ok(keyspaces)
+    pub async fn update_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        self.update_many_with_type(iter, chunk_size, BatchType::Logged)
+            .await
     }
 
-    /// Gets the currently active keyspace for this session
+    /// Updates multiple entities in the database using batch operations of the given type
     ///
-    /// Returns the name of the keyspace that is currently being used by
-    /// the session, if any.
+    /// Works like `update_many`, but allows selecting the batch type. Unlogged batches
+    /// skip the batch log and are considerably faster for same-partition bulk writes,
+    /// at the cost of atomicity across partitions.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being updated
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - A slice of entities to update
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
+    /// * `batch_type` - The type of batch to use (`Logged` or `Unlogged`)
     ///
     /// # Returns
     ///
-    /// An `Option<String>` containing the keyspace name, or `None` if no keyspace is set.
+    /// A `Result` indicating success or failure of the batch update operation.
+    /// Returns `Error::UnsupportedBatchType` for `BatchType::Counter`, because model
+    /// updates are not counter updates.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::batch::BatchType;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     if let Some(keyspace) = client.get_keyspace() {
-    ///         println!("Current keyspace: {}", keyspace);
-    ///     } else {
-    ///         println!("No keyspace is currently set");
-    ///     }
+    ///     let users: Vec<User> = vec![/* ... users to update ... */];
+    ///
+    ///     client.update_many_with_type(&users, 1000, BatchType::Unlogged).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub fn get_keyspace(&self) -> Option<String> {
-        let keyspace = self.session.get_session().get_keyspace();
+    pub async fn update_many_with_type<'a, E>(
+        &self,
+        iter: &[E],
+        chunk_size: usize,
+        batch_type: BatchType,
+    ) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        let new_batch: fn() -> _ = match batch_type {
+            BatchType::Logged => E::batch,
+            BatchType::Unlogged => E::unlogged_batch,
+            BatchType::Counter => return Err(Error::UnsupportedBatchType(batch_type)),
+        };
 
-        keyspace.map(|k| k.to_string())
+        Self::log_batch("Update", batch_type, iter.len(), chunk_size);
+
+        for (index, chunk) in iter.chunks(chunk_size.max(1)).enumerate() {
+            self.batch_apply_params(new_batch())
+                .chunked_update(&self.session, chunk, chunk.len())
+                .await
+                .map_err(|error| Self::batch_chunk_error(index, error))?;
+        }
+
+        Ok(())
     }
 
-    /// Sets the active keyspace for this session
+    /// Increments a counter column of the row with the given primary key
     ///
-    /// Changes the current keyspace context for the session. All subsequent
-    /// queries will be executed in the context of this keyspace unless
-    /// explicitly qualified with a different keyspace name.
+    /// Charybdis models don't expose counter updates, so this method issues
+    /// `UPDATE <table> SET <column> = <column> + <by> WHERE <primary key>` as raw CQL,
+    /// applying any CRUD parameters configured for this client instance. A negative
+    /// `by` decrements the counter. Counter updates are not idempotent, so they are
+    /// never retried, even if the retry policy has `retry_non_idempotent` set: a
+    /// retried update that timed out after being applied would count twice. Using a
+    /// column that is not a counter fails with the driver error.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type owning the counter column
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to use
+    /// * `primary_key` - The primary key values of the row, in the order of the model's
+    ///   partition and clustering keys
+    /// * `column` - The name of the counter column
+    /// * `by` - The amount to add to the counter
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the operation.
+    /// A `Result` indicating success or failure of the update operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::types::Counter;
+    ///
+    /// // Assuming you have a `PageViews` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = page_views,
+    /// #       partition_keys = [page],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct PageViews {
+    /// #     page: String,
+    /// #     views: Option<Counter>,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     client.use_keyspace("my_application").await?;
+    ///     client
+    ///         .increment::<PageViews>(("/home".to_string(),), "views", 1)
+    ///         .await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn use_keyspace(&self, name: &str) -> Result<()> {
-        self.session.get_session().use_keyspace(name, true).await?;
+    pub async fn increment<E>(
+        &self,
+        primary_key: E::PrimaryKey,
+        column: &str,
+        by: i64,
+    ) -> Result<()>
+    where
+        E: Model + Sync + Send,
+    {
+        let query = Self::counter_query(E::FIND_BY_PRIMARY_KEY_QUERY, column, by);
+
+        debug!("Increment query: {}", query);
+
+        match &self.crud_params {
+            Some(params) => {
+                let params = CrudParams {
+                    retry: None,
+                    ..params.clone()
+                };
+                self.execute_with(&params, &query, primary_key).await?
+            }
+            None => self.execute(&query, primary_key).await?,
+        };
 
         Ok(())
     }
 
-    // Drops and recreates a keyspace
+    /// Inserts a single entity into the database
     ///
-    /// This method first drops the specified keyspace (if it exists) and then
-    /// creates it again with default replication settings. This is useful for
-    /// resetting a keyspace to a clean state.
+    /// This method takes an entity that implements the `Insert` trait and
+    /// generates an insert query automatically. The entity's `insert()` method
+    /// is called to create the appropriate Charybdis query.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to recreate
+    /// * `entity` - The entity instance to insert
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the operation.
+    /// A `Result` indicating success or failure of the insert operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
-    /// use grapple_db::scylla::Result;
-    ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<()> {
-    ///     let client = Client::default().await?;
-    ///
-    ///     client.recreate_keyspace("test_keyspace").await?;
+    /// use grapple_db::scylla::operations::New;
     ///
-    ///     Ok(())
-    /// }
-    /// ```
-    pub async fn recreate_keyspace(&self, name: &str) -> Result<()> {
-        self.drop_keyspace(name).await?;
-        self.create_keyspace(name).await?;
-
-        Ok(())
-    }
-
-    /// Recreates a keyspace and returns the client instance (builder pattern)
-    ///
-    /// This is a convenience method that combines `recreate_keyspace` with the
-    /// builder pattern, allowing you to chain method calls during client setup.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The name of the keyspace to recreate
-    ///
-    /// # Returns
-    ///
-    /// A `Result` containing the client instance for method chaining.
-    ///
-    /// # Examples
-    ///
-    /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, ConnectionParams};
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [name],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     name: String,
+    /// # }
+    /// #
+    /// # impl User{
+    /// #    pub fn new(name: &str) -> Self {
+    /// #        Self { name: name.to_string() }
+    /// #    }
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::connect(&ConnectionParams::default())
-    ///         .await?
-    ///         .with_recreate_keyspace("test_keyspace")
-    ///         .await?;
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let user = User::new("John Doe");
+    ///     client.insert(&user).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn with_recreate_keyspace(self, name: &str) -> Result<Self> {
-        self.recreate_keyspace(name).await?;
+    pub async fn insert<E>(&self, entity: &E) -> Result<()>
+    where
+        E: Model + Insert + Sync + Send + 'static,
+    {
+        self.with_retry(true, move || self.insert_query(entity.insert()))
+            .await?;
 
-        Ok(self)
+        Ok(())
     }
 
-    /// Creates a new keyspace if it doesn't already exist
+    /// Inserts a single entity and returns the row as stored in the database
     ///
-    /// This method executes a `CREATE KEYSPACE IF NOT EXISTS` statement with
-    /// SimpleStrategy replication and a replication factor of 1. This is suitable
-    /// for development and testing environments.
+    /// This method works like `insert`, then re-reads the row by its primary key,
+    /// so any database-side defaults or coercions are reflected in the returned
+    /// entity. If the row is gone by the time it is read (e.g. it expired
+    /// immediately), `Error::NotFound` is returned.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to create
+    /// * `entity` - The entity instance to insert
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the operation.
+    /// A `Result` containing the stored entity.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// #     name: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     client.create_keyspace("my_application").await?;
+    ///     let user = User { id: "1".to_string(), name: "John".to_string() };
+    ///     let stored = client.insert_returning(&user).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn create_keyspace(&self, name: &str) -> Result<()> {
-        let query = format!("CREATE KEYSPACE IF NOT EXISTS {name} WITH REPLICATION = {{ 'class' : 'SimpleStrategy', 'replication_factor' : 1 }};");
+    pub async fn insert_returning<E>(&self, entity: &E) -> Result<E>
+    where
+        E: Model + Insert + Sync + Send + 'static,
+    {
+        self.insert(entity).await?;
+
+        self.get_optional(entity.maybe_find_by_primary_key())
+            .await?
+            .ok_or_else(|| Error::NotFound(E::DB_MODEL_NAME.to_string()))
+    }
+
+    /// Internal method for executing insert queries
+    ///
+    /// This method handles the actual execution of insert queries with proper
+    /// parameter application and logging.
+    async fn insert_query<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelMutation>,
+    ) -> Result<()>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send,
+    {
+        debug!("Insert query: {}", query.query_string());
 
-        self.execute(&query, &[]).await?;
+        _ = self
+            .query_apply_params(query)
+            .execute(&self.session)
+            .await?;
 
         Ok(())
     }
 
-    /// Drops a keyspace if it exists
+    /// Inserts multiple entities into the database using batch operations
     ///
-    /// This method executes a `DROP KEYSPACE IF EXISTS` statement for the
-    /// specified keyspace. It's safe to call even if the keyspace doesn't exist.
+    /// This method efficiently inserts a large number of entities by grouping
+    /// them into batches of the specified size. This is much more efficient
+    /// than inserting entities one by one.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to drop
+    /// * `iter` - A slice of entities to insert
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the operation.
+    /// A `Result` indicating success or failure of the batch insert operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     client.drop_keyspace("old_keyspace").await?;
+    ///     let users: Vec<User> = vec![/* ... users to insert ... */];
+    ///     client.insert_many(&users, 1000).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn drop_keyspace(&self, name: &str) -> Result<()> {
-        let query = format!("DROP KEYSPACE IF EXISTS {name};");
-
-        self.execute(&query, &[]).await?;
-
-        Ok(())
+    pub async fn insert_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        self.insert_many_with_type(iter, chunk_size, BatchType::Logged)
+            .await
     }
 
-    /// Creates a keyspace and returns the client instance (builder pattern)
+    /// Inserts multiple entities into the database, deduplicating them by primary key
     ///
-    /// This is a convenience method that combines `create_keyspace` with the
-    /// builder pattern, allowing you to chain method calls during client setup.
+    /// Works like `insert_many`, but entities sharing a primary key are handled
+    /// according to `strategy` instead of silently overwriting each other in the
+    /// batch: either only the last occurrence is inserted, or the whole insert
+    /// fails before anything is written.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to create
+    /// * `iter` - A slice of entities to insert
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
+    /// * `strategy` - How to handle entities sharing a primary key
     ///
     /// # Returns
     ///
-    /// A `Result` containing the client instance for method chaining.
+    /// A `Result` indicating success or failure of the batch insert operation.
+    /// With `DedupStrategy::Error`, a duplicate fails with `Error::DuplicatePrimaryKey`
+    /// carrying the index of the first duplicate.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, ConnectionParams};
+    /// use grapple_db::scylla::{Client, DedupStrategy};
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default, Clone)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::connect(&ConnectionParams::default())
-    ///         .await?
-    ///         .with_keyspace("my_application")
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let users: Vec<User> = vec![/* ... users to import ... */];
+    ///     client
+    ///         .insert_many_dedup(&users, 1000, DedupStrategy::KeepLast)
     ///         .await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn with_keyspace(self, name: &str) -> Result<Self> {
-        self.create_keyspace(name).await?;
+    pub async fn insert_many_dedup<'a, E>(
+        &self,
+        iter: &[E],
+        chunk_size: usize,
+        strategy: DedupStrategy,
+    ) -> Result<()>
+    where
+        E: ModelBatch<'a> + Model + Clone + Sync + Send + 'a,
+        E::PrimaryKey: Eq + std::hash::Hash,
+    {
+        // Index of the last occurrence of every primary key
+        let mut last = HashMap::with_capacity(iter.len());
 
-        Ok(self)
+        for (index, entity) in iter.iter().enumerate() {
+            let duplicate = last.insert(entity.primary_key_values(), index).is_some();
+
+            if duplicate && strategy == DedupStrategy::Error {
+                return Err(Error::DuplicatePrimaryKey {
+                    table: E::DB_MODEL_NAME.to_string(),
+                    index,
+                });
+            }
+        }
+
+        if last.len() == iter.len() {
+            return self.insert_many(iter, chunk_size).await;
+        }
+
+        debug!(
+            "Dropped {} entities with duplicate primary keys",
+            iter.len() - last.len()
+        );
+
+        let entities: Vec<E> = iter
+            .iter()
+            .enumerate()
+            .filter(|(index, entity)| last.get(&entity.primary_key_values()) == Some(index))
+            .map(|(_, entity)| entity.clone())
+            .collect();
+
+        self.insert_many(&entities, chunk_size).await
     }
 
-    /// Creates multiple keyspaces and returns the client instance (builder pattern)
+    /// Inserts multiple entities into the database using unlogged batch operations
     ///
-    /// This method creates multiple keyspaces in sequence and returns the client
-    /// instance for method chaining. Useful when setting up multiple keyspaces
-    /// during application initialization.
+    /// This is a shortcut for `insert_many_with_type` with `BatchType::Unlogged`.
+    /// Unlogged batches are much faster for same-partition bulk writes, but are not
+    /// atomic across partitions.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `names` - A slice of keyspace names to create
+    /// * `iter` - A slice of entities to insert
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
     ///
     /// # Returns
     ///
-    /// A `Result` containing the client instance for method chaining.
+    /// A `Result` indicating success or failure of the batch insert operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, ConnectionParams};
+    /// use grapple_db::scylla::Client;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::connect(&ConnectionParams::default())
-    ///         .await?
-    ///         .with_keyspaces(&["users", "products", "orders"])
-    ///         .await?;
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let users: Vec<User> = vec![/* ... users to insert ... */];
+    ///     client.insert_many_unlogged(&users, 1000).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn with_keyspaces(self, names: &[&str]) -> Result<Self> {
-        for name in names {
-            self.create_keyspace(name).await?;
-        }
-
-        Ok(self)
+    pub async fn insert_many_unlogged<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        self.insert_many_with_type(iter, chunk_size, BatchType::Unlogged)
+            .await
     }
 
-    /// Drops a keyspace and returns the client instance (builder pattern)
+    /// Inserts multiple entities into the database using batch operations of the given type
     ///
-    /// This is a convenience method that combines `drop_keyspace` with the
-    /// builder pattern, allowing you to chain method calls during client setup.
+    /// Works like `insert_many`, but allows selecting the batch type.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being inserted
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the keyspace to drop
+    /// * `iter` - A slice of entities to insert
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
+    /// * `batch_type` - The type of batch to use (`Logged` or `Unlogged`)
     ///
     /// # Returns
     ///
-    /// A `Result` containing the client instance for method chaining.
+    /// A `Result` indicating success or failure of the batch insert operation.
+    /// Returns `Error::UnsupportedBatchType` for `BatchType::Counter`, because counter
+    /// batches cannot contain inserts.
+    ///
+    /// Chunks are applied in order. If a chunk fails, `Error::BatchChunk` reports the index
+    /// of the failing chunk and the number of chunks applied before it, so the insert can be
+    /// resumed from the failing chunk.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, ConnectionParams};
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::batch::BatchType;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::connect(&ConnectionParams::default())
-    ///         .await?
-    ///         .without_keyspace("old_keyspace")
-    ///         .await?;
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let users: Vec<User> = vec![/* ... users to insert ... */];
+    ///     client.insert_many_with_type(&users, 1000, BatchType::Logged).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn without_keyspace(self, name: &str) -> Result<Self> {
-        self.drop_keyspace(name).await?;
+    pub async fn insert_many_with_type<'a, E>(
+        &self,
+        iter: &[E],
+        chunk_size: usize,
+        batch_type: BatchType,
+    ) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        let new_batch: fn() -> _ = match batch_type {
+            BatchType::Logged => E::batch,
+            BatchType::Unlogged => E::unlogged_batch,
+            BatchType::Counter => return Err(Error::UnsupportedBatchType(batch_type)),
+        };
 
-        Ok(self)
+        Self::log_batch("Insert", batch_type, iter.len(), chunk_size);
+
+        for (index, chunk) in iter.chunks(chunk_size.max(1)).enumerate() {
+            self.batch_apply_params(new_batch())
+                .chunked_insert(&self.session, chunk, chunk.len())
+                .await
+                .map_err(|error| Self::batch_chunk_error(index, error))?;
+        }
+
+        Ok(())
     }
 
-    /// Drops multiple keyspaces and returns the client instance (builder pattern)
+    /// Deletes a single entity from the database
     ///
-    /// This method drops multiple keyspaces in sequence and returns the client
-    /// instance for method chaining. Useful when cleaning up multiple keyspaces
-    /// during application shutdown or testing.
+    /// This method takes an entity that implements the `Delete` trait and
+    /// generates a delete query automatically. The entity's `delete()` method
+    /// is called to create the appropriate Charybdis query.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being deleted
     ///
     /// # Arguments
     ///
-    /// * `names` - A slice of keyspace names to drop
+    /// * `entity` - The entity instance to delete
     ///
     /// # Returns
     ///
-    /// A `Result` containing the client instance for method chaining.
+    /// A `Result` indicating success or failure of the delete operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, ConnectionParams};
+    /// use grapple_db::scylla::Client;
+    ///
+    /// // Assuming you have a User model defined
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::connect(&ConnectionParams::default())
-    ///         .await?
-    ///         .without_keyspaces(&["test_users", "test_products"])
-    ///         .await?;
+    ///     let client = Client::default().await?;
+    ///     
+    ///     /// Get user somehow
+    ///     let user = User::default();
+    ///     client.delete(&user).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn without_keyspaces(self, names: &[&str]) -> Result<Self> {
-        for name in names {
-            self.drop_keyspace(name).await?;
-        }
+    pub async fn delete<E>(&self, entity: &E) -> Result<()>
+    where
+        E: Model + Delete + Sync + Send + 'static,
+    {
+        self.delete_query(entity.delete()).await?;
 
-        Ok(self)
+        Ok(())
     }
-}
 
-// ================================================================================================
-// Utility methods
-// ================================================================================================
-impl Client {
-    /// Executes a raw CQL query with the provided values
+    /// Deletes a single row given only its primary key values
     ///
-    /// This method provides direct access to the underlying ScyllaDB session
-    /// for executing custom CQL queries that are not covered by the high-level
-    /// CRUD operations. Use this for complex queries, DDL statements, or
-    /// database administration tasks.
+    /// Unlike `delete`, this method doesn't need a constructed entity. It issues the
+    /// model's delete query, `DELETE FROM <table> WHERE <primary key> = ?`, binding
+    /// the given values. The query is automatically enhanced with any CRUD parameters
+    /// configured for this client instance. Deleting a missing row is not an error.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being deleted
     ///
     /// # Arguments
     ///
-    /// * query - The CQL query string to execute
-    /// * values - Values to bind to the query parameters
+    /// * `primary_key` - The primary key values of the row, in the order of the model's
+    ///   partition and clustering keys
     ///
     /// # Returns
     ///
-    /// A Result containing the QueryResult or an error.
+    /// A `Result` indicating success or failure of the delete operation.
     ///
     /// # Examples
+    ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a User model defined
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
-    ///
-    ///     let result = client.execute("SELECT COUNT(*) FROM users WHERE active = ?",
-    ///         (true,)).await?;  
+    ///     
+    ///     client.delete_by_pk::<User>(("1".to_string(),)).await?;
+    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn execute(&self, query: &str, values: impl SerializeRow) -> Result<QueryResult> {
-        debug!("Executing query: {}", query);
+    pub async fn delete_by_pk<E>(&self, primary_key: E::PrimaryKey) -> Result<()>
+    where
+        E: Model + Sync + Send,
+    {
+        debug!("Delete by primary key query: {}", E::DELETE_QUERY);
 
-        let res = self.session.execute_unpaged(query, values).await?;
+        self.execute(E::DELETE_QUERY, primary_key).await?;
 
-        Ok(res)
+        Ok(())
     }
 
-    /// Executes CQL queries from a file
+    /// Internal method for executing delete queries
     ///
-    /// This method reads a file containing CQL statements separated by semicolons
-    /// and executes them sequentially. This is useful for running initialization
-    /// scripts, schema migrations, or bulk data operations.
+    /// This method handles the actual execution of delete queries with proper
+    /// parameter application and logging.
+    async fn delete_query<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelMutation>,
+    ) -> Result<()>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send,
+    {
+        debug!("Delete query: {}", query.query_string());
+
+        _ = self
+            .query_apply_params(query)
+            .execute(&self.session)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes multiple entities from the database using batch operations
     ///
-    /// # Arguments
+    /// This method efficiently deletes a large number of entities by grouping
+    /// them into batches of the specified size. This reduces the number of
+    /// round trips to the database and improves performance.
     ///
-    /// * `filename` - Path to the file containing CQL statements
+    /// # Type Parameters
     ///
-    /// # Returns
+    /// * `E` - The entity/model type being deleted
     ///
-    /// A `Result` indicating success or failure of the file execution.
+    /// # Arguments
     ///
-    /// # File Format
+    /// * `iter` - A slice of entities to delete
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
     ///
-    /// The file should contain CQL statements separated by semicolons:
-    /// ```sql
-    /// CREATE TABLE users (id UUID PRIMARY KEY, name TEXT);
-    /// INSERT INTO users (id, name) VALUES (uuid(), 'John Doe');
-    /// ```
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the batch delete operation.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use grapple_db::scylla::Client;
     ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::default().await?;
     ///     
-    ///     client.execute_file("database/schema.cql").await?;
+    ///     let users: Vec<User> = vec![/* ... users to delete ... */];
+    ///     client.delete_many(&users, 1000).await?;
     ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn execute_file(&self, filename: &str) -> Result<()> {
-        debug!("Init file '{}'", filename);
-
-        let current_path = std::env::current_dir().unwrap();
-        let file_path = Path::new(filename);
-        let full_path = current_path.join(file_path);
-
-        let raw_queries = tokio::fs::read_to_string(full_path)
+    pub async fn delete_many<'a, E>(&self, iter: &[E], chunk_size: usize) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        self.delete_many_with_type(iter, chunk_size, BatchType::Logged)
             .await
-            .unwrap_or_else(|_| panic!("Could not read file"));
-
-        let queries = raw_queries
-            .split(";")
-            .map(|query| query.trim())
-            .collect::<Vec<&str>>();
-
-        for query in queries {
-            if query.is_empty() {
-                continue;
-            }
-
-            self.execute(query, &[]).await?;
-        }
-
-        Ok(())
     }
 
-    /// Runs database migrations using Charybdis migration builder
+    /// Deletes multiple entities from the database using batch operations of the given type
     ///
-    /// This method executes database schema migrations using the Charybdis
-    /// migration framework. It can optionally drop and recreate the keyspace
-    /// before running migrations, which is useful for development environments.
+    /// Works like `delete_many`, but allows selecting the batch type.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type being deleted
     ///
     /// # Arguments
     ///
-    /// * `session` - The ScyllaDB session to use for migrations
-    /// * `use_keyspace` - Optional keyspace name to target for migrations
+    /// * `iter` - A slice of entities to delete
+    /// * `chunk_size` - The number of entities to include in each batch; `0` is treated as `1`
+    /// * `batch_type` - The type of batch to use (`Logged` or `Unlogged`)
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or failure of the migration process.
+    /// A `Result` indicating success or failure of the batch delete operation.
+    /// Returns `Error::UnsupportedBatchType` for `BatchType::Counter`, because counter
+    /// batches cannot contain deletes.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use grapple_db::scylla::{Client, client::Session};
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::batch::BatchType;
     ///
-    /// async fn run_migrations(session: &Session) -> Result<(), Box<dyn std::error::Error>> {
-    ///     Client::migrate(session, &Some("my_keyspace".to_string())).await?;
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let users: Vec<User> = vec![/* ... users to delete ... */];
+    ///     client.delete_many_with_type(&users, 1000, BatchType::Unlogged).await?;
+    ///     
     ///     Ok(())
     /// }
     /// ```
-    pub async fn migrate(session: &Session, use_keyspace: &Option<String>) -> Result<()> {
-        debug!("Migration started");
+    pub async fn delete_many_with_type<'a, E>(
+        &self,
+        iter: &[E],
+        chunk_size: usize,
+        batch_type: BatchType,
+    ) -> Result<()>
+    where
+        E: ModelBatch<'a> + Sync + Send + 'a,
+    {
+        let new_batch: fn() -> _ = match batch_type {
+            BatchType::Logged => E::batch,
+            BatchType::Unlogged => E::unlogged_batch,
+            BatchType::Counter => return Err(Error::UnsupportedBatchType(batch_type)),
+        };
 
-        let mut builder = MigrationBuilder::new();
+        Self::log_batch("Delete", batch_type, iter.len(), chunk_size);
 
-        if let Some(keyspace) = use_keyspace {
-            builder = builder.keyspace(keyspace.to_owned());
+        for (index, chunk) in iter.chunks(chunk_size.max(1)).enumerate() {
+            self.batch_apply_params(new_batch())
+                .chunked_delete(&self.session, chunk, chunk.len())
+                .await
+                .map_err(|error| Self::batch_chunk_error(index, error))?;
         }
 
-        let migration = builder.build(session).await;
-
-        migration.run().await;
-
         Ok(())
     }
 
-    /// Internal method for applying CRUD parameters to batch operations
+    /// Creates a stream for efficiently processing large result sets
     ///
-    /// This method applies the client's CRUD parameters (consistency, timeout,
-    /// timestamp) to a Charybdis model batch if parameters are configured.
+    /// This method executes a query that returns a stream of results, which is
+    /// useful for processing large datasets without loading everything into memory
+    /// at once. The stream can be used with pagination or consumed incrementally.
     ///
     /// # Type Parameters
     ///
-    /// * `Val` - The type of values being serialized for the batch
-    /// * `E` - The entity/model type being batched
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
     ///
     /// # Arguments
     ///
-    /// * `batch` - The Charybdis model batch to enhance with parameters
+    /// * `query` - A Charybdis query configured to return a stream of results
     ///
     /// # Returns
     ///
-    /// The batch with applied CRUD parameters, or the original batch if no parameters are set.
-    fn batch_apply_params<'a, Val, E>(
-        &self,
-        batch: CharybdisModelBatch<'a, Val, E>,
-    ) -> CharybdisModelBatch<'a, Val, E>
+    /// A `Result` containing a `CharybdisModelStream` for processing results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use futures::StreamExt;
+    /// use grapple_db::scylla::operations::Find;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let mut stream = client.stream(User::find_all()).await?;
+    ///     while let Some(user) = stream.next().await {
+    ///          match user {
+    ///              Ok(user) => println!("User: {:?}", user),
+    ///              Err(e) => eprintln!("Error: {:?}", e),
+    ///          }
+    ///      }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+    ) -> Result<CharybdisModelStream<E>>
     where
         Val: SerializeRow + Sync + Send,
-        E: ModelBatch<'a>,
+        E: Model + Sync + Send + 'static,
     {
-        if let Some(params) = &self.crud_params {
-            params.apply_batch(batch)
-        } else {
-            batch
-        }
+        debug!("Stream query: {}", query.query_string());
+
+        let res = self
+            .query_apply_params(query)
+            .execute(&self.session)
+            .await?;
+
+        Ok(res)
     }
 
-    /// Internal method for applying CRUD parameters to queries
+    /// Creates a paginated stream from a query
     ///
-    /// This method applies the client's CRUD parameters (consistency, timeout,
-    /// timestamp) to a Charybdis query if parameters are configured.
+    /// This is a shorthand for `stream` followed by `PagableCharybdisStream::new`.
+    /// Unlike `paged`, no count query is run, so the total number of items is unknown.
     ///
     /// # Type Parameters
     ///
     /// * `Val` - The type of values being serialized for the query
-    /// * `E` - The entity/model type being queried
-    /// * `Qe` - The query executor type
+    /// * `E` - The entity/model type being streamed
     ///
     /// # Arguments
     ///
-    /// * `query` - The Charybdis query to enhance with parameters
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `per_page` - The number of items to retrieve per page
     ///
     /// # Returns
     ///
-    /// The query with applied CRUD parameters, or the original query if no parameters are set.
-    fn query_apply_params<'a, Val, E, Qe>(
+    /// A `Result` containing a `PagableCharybdisStream` over the query results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::operations::Find;
+    /// use grapple_db::Pagable;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let mut pages = client.paged_stream(User::find_all(), 20).await?;
+    ///
+    ///     while let Some(users) = pages.next_page().await {
+    ///         // Render the page
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn paged_stream<'a, Val, E>(
         &self,
-        query: CharybdisQuery<'a, Val, E, Qe>,
-    ) -> CharybdisQuery<'a, Val, E, Qe>
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        per_page: usize,
+    ) -> Result<PagableCharybdisStream<E>>
     where
         Val: SerializeRow + Sync + Send,
-        E: Model + Sync + Send,
-        Qe: QueryExecutor<E>,
+        E: Model + Sync + Send + 'static,
     {
-        if let Some(params) = &self.crud_params {
-            params.apply_query(query)
-        } else {
-            query
-        }
-    }
-}
-
-// region:    --- Tests
-
-#[cfg(test)]
-mod tests {
-    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
-
-    use super::*;
+        let stream = self.stream(query).await?;
 
-    use crate::scylla::{
-        charybdis::{self, macros::charybdis_model, types::Text},
-        Client, ConnectionParams,
-    };
+        Ok(PagableCharybdisStream::new(stream, per_page))
+    }
 
-    #[charybdis_model(
-        table_name = users,
-        partition_keys = [id],
-        clustering_keys = [],
+    /// Creates a paginated stream that knows the total number of items
+    ///
+    /// The count and the stream are started together, so the count doesn't delay
+    /// the first page more than necessary. The count goes through `count`, so it is
+    /// served from the count cache when one is enabled.
+    ///
+    /// A query can only be executed once, so this method takes a function building
+    /// the query instead of the query itself; a query constructor such as
+    /// `User::find_all` can be passed directly.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A function building the Charybdis query to paginate
+    /// * `per_page` - The number of items to retrieve per page
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `PagableCharybdisStream` reporting `total_items`
+    /// and `total_pages`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::operations::Find;
+    /// use grapple_db::Pagable;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let mut pages = client.paged(User::find_all, 20).await?;
+    ///     println!("{:?} pages in total", pages.total_pages());
+    ///
+    ///     while let Some(users) = pages.next_page().await {
+    ///         // Render the page
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn paged<'a, Val, E, F>(
+        &self,
+        query: F,
+        per_page: usize,
+    ) -> Result<PagableCharybdisStream<E>>
+    where
+        Val: SerializeRow + Sync + Send + Debug,
+        E: Model + Sync + Send + 'static,
+        F: Fn() -> CharybdisQuery<'a, Val, E, ModelStream>,
+    {
+        let (count, stream) = tokio::try_join!(self.count(query()), self.stream(query()))?;
 
-        global_secondary_indexes = [name],
-        local_secondary_indexes = [],
-    )]
-    #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Tst {
-        id: Text,
-        name: Option<Text>,
+        Ok(PagableCharybdisStream::with_count(stream, per_page, count))
     }
 
-    impl Tst {
-        fn with_id(id: &str) -> Self {
-            Self {
-                id: id.to_string(),
-                name: None,
-            }
-        }
+    /// Creates a stream that fetches rows ahead of the consumer
+    ///
+    /// The regular stream pulls one row at a time when the consumer asks for it. This
+    /// method drives the stream in a background task that keeps up to `buffer` rows
+    /// ready, so fetching from the network overlaps with processing. When the buffer
+    /// is full, the background task waits for the consumer (backpressure). Dropping
+    /// the returned stream stops the background task.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `buffer` - The maximum number of rows fetched ahead; `0` disables buffering
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of entities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use futures::StreamExt;
+    /// use grapple_db::scylla::operations::Find;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let stream = client.stream_buffered(User::find_all(), 100).await?;
+    ///     let mut stream = std::pin::pin!(stream);
+    ///
+    ///     while let Some(user) = stream.next().await {
+    ///         println!("User: {:?}", user?);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_buffered<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        buffer: usize,
+    ) -> Result<impl Stream<Item = Result<E>>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+    {
+        let mut rows = self
+            .stream(query)
+            .await?
+            .map(|row| row.map_err(Error::from));
 
-        fn with_name(mut self, name: impl Into<String>) -> Self {
-            _ = self.name.insert(name.into());
-            self
+        if buffer == 0 {
+            return Ok(rows.left_stream());
         }
-    }
 
-    async fn get_client() -> Client {
-        let params = ConnectionParams {
-            migrate: false,
-            use_keyspace: Some("test".into()),
+        let (tx, rx) = tokio::sync::mpsc::channel(buffer);
 
-            ..Default::default()
-        };
+        tokio::spawn(async move {
+            while let Some(row) = rows.next().await {
+                // The receiver was dropped, stop fetching
+                if tx.send(row).await.is_err() {
+                    break;
+                }
+            }
+        });
 
-        let client = Client::connect(&params).await.unwrap();
+        let buffered =
+            futures::stream::unfold(
+                rx,
+                |mut rx| async move { rx.recv().await.map(|row| (row, rx)) },
+            );
 
-        client
-            .execute(
-                "
-        CREATE TABLE IF NOT EXISTS users (
-            id text PRIMARY KEY,
-            name text
-        );
-        ",
-                &[],
+        Ok(buffered.right_stream())
+    }
+
+    /// Creates a stream that can be cancelled with a `CancellationToken`
+    ///
+    /// Long-running streams (e.g. analytics exports) can be stopped when the consumer
+    /// goes away. Once the token is cancelled, the stream stops yielding items, even
+    /// while waiting for the next page, and drops the underlying driver stream, so no
+    /// further pages are fetched. The prepared statement stays in the session cache
+    /// and can be reused by later queries.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `token` - The token cancelling the stream
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of entities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{CancellationToken, Client};
+    /// use futures::StreamExt;
+    /// use grapple_db::scylla::operations::Find;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     let token = CancellationToken::new();
+    ///
+    ///     // Cancel the token when the consumer disconnects
+    ///     let stream = client.stream_cancellable(User::find_all(), token.clone()).await?;
+    ///     let mut stream = std::pin::pin!(stream);
+    ///
+    ///     while let Some(user) = stream.next().await {
+    ///         println!("User: {:?}", user?);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_cancellable<'a, Val, E>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        token: CancellationToken,
+    ) -> Result<impl Stream<Item = Result<E>>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+    {
+        let rows = self.stream(query).await?;
+
+        let stream = futures::stream::unfold((rows, token), |(mut rows, token)| async move {
+            let row = tokio::select! {
+                biased;
+                _ = token.cancelled() => None,
+                row = rows.next() => row,
+            }?;
+
+            Some((row.map_err(Error::from), (rows, token)))
+        });
+
+        Ok(stream)
+    }
+
+    /// Creates a stream reporting the number of consumed rows to a callback
+    ///
+    /// Long-running exports can report their progress without wrapping the stream by hand.
+    /// `on_progress` is invoked with the number of rows yielded so far every `every` rows,
+    /// and once more when the stream is exhausted, with the final count, unless that count
+    /// was just reported. Rows are counted as they are consumed, so the callback never runs
+    /// ahead of the consumer. An `every` of `0` only reports the final count.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    /// * `F` - The progress callback
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `every` - The number of rows between two progress reports
+    /// * `on_progress` - The callback receiving the number of rows consumed so far
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of entities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use futures::StreamExt;
+    /// use grapple_db::scylla::operations::Find;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let stream = client
+    ///         .stream_with_progress(User::find_all(), 1000, |count| {
+    ///             println!("Exported {} users", count);
+    ///         })
+    ///         .await?;
+    ///     let mut stream = std::pin::pin!(stream);
+    ///
+    ///     while let Some(user) = stream.next().await {
+    ///         println!("User: {:?}", user?);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_with_progress<'a, Val, E, F>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        every: usize,
+        on_progress: F,
+    ) -> Result<impl Stream<Item = Result<E>>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+        F: FnMut(usize),
+    {
+        let rows = self.stream(query).await?;
+
+        let stream = futures::stream::unfold(
+            (rows, 0, on_progress),
+            move |(mut rows, count, mut on_progress)| async move {
+                let Some(row) = rows.next().await else {
+                    // Report the final count once, unless it was just reported
+                    if every == 0 || count % every != 0 || count == 0 {
+                        on_progress(count);
+                    }
+                    return None;
+                };
+
+                let count = count + 1;
+
+                if every > 0 && count % every == 0 {
+                    on_progress(count);
+                }
+
+                Some((row.map_err(Error::from), (rows, count, on_progress)))
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Creates a stream for a query that requires `ALLOW FILTERING`
+    ///
+    /// This method appends `ALLOW FILTERING` to the provided CQL `SELECT` query (unless it
+    /// is already present) and streams the results as entities. Filtering queries may scan
+    /// large parts of the table, so every call emits a `warn!` log to keep their use
+    /// intentional and visible. Prefer indexed queries in production code.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A CQL `SELECT` query returning rows of the model's table
+    /// * `values` - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CharybdisModelStream` for processing results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use futures::StreamExt;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// #     age: i32,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let mut stream = client
+    ///         .stream_filtered::<_, User>("SELECT * FROM users WHERE age > ?", (18,))
+    ///         .await?;
+    ///
+    ///     while let Some(Ok(user)) = stream.next().await {
+    ///         println!("User: {:?}", user);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_filtered<Val, E>(
+        &self,
+        query: &str,
+        values: Val,
+    ) -> Result<CharybdisModelStream<E>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+    {
+        let mut query = query.trim().trim_end_matches(';').trim_end().to_string();
+
+        if !query.to_uppercase().ends_with("ALLOW FILTERING") {
+            query.push_str(" ALLOW FILTERING");
+        }
+
+        warn!(
+            "Query uses ALLOW FILTERING and may scan the whole table: {}",
+            query
+        );
+
+        self.stream(E::find(&query, values)).await
+    }
+
+    /// Creates a stream for a query against a table in the specified keyspace
+    ///
+    /// This method qualifies the table in the `FROM` clause of the provided CQL
+    /// `SELECT` query with the keyspace (unless it is already qualified) and streams
+    /// the results as entities. Unlike `use_keyspace`, the session's active keyspace
+    /// is left unchanged, so other queries are not affected.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being streamed
+    ///
+    /// # Arguments
+    ///
+    /// * `keyspace` - The keyspace containing the queried table
+    /// * `query` - A CQL `SELECT` query returning rows of the model's table
+    /// * `values` - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CharybdisModelStream` for processing results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use futures::StreamExt;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let mut stream = client
+    ///         .stream_in_keyspace::<_, User>("archive", "SELECT * FROM users WHERE id = ?", ("1",))
+    ///         .await?;
+    ///
+    ///     while let Some(Ok(user)) = stream.next().await {
+    ///         println!("User: {:?}", user);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_in_keyspace<Val, E>(
+        &self,
+        keyspace: &str,
+        query: &str,
+        values: Val,
+    ) -> Result<CharybdisModelStream<E>>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+    {
+        let query = Self::qualify_table(query, keyspace);
+
+        self.stream(E::find(&query, values)).await
+    }
+
+    /// Creates a stream for a pre-built driver statement
+    ///
+    /// This method executes a hand-built `Statement` (e.g. with a custom page size or
+    /// a query Charybdis doesn't generate) and streams the returned rows, deserializing
+    /// them into entities. Rows are fetched page by page as the stream is consumed.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type the rows are deserialized into
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to execute
+    /// * `values` - Values to bind to the statement parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of entities. If the returned columns don't match
+    /// the model, `Error::TypeCheck` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::unprepared::Statement;
+    /// use futures::StreamExt;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let mut statement = Statement::new("SELECT * FROM users WHERE id IN ?");
+    ///     statement.set_page_size(100);
+    ///
+    ///     let stream = client
+    ///         .stream_statement::<User>(statement, (vec!["1", "2"],))
+    ///         .await?;
+    ///     let mut stream = std::pin::pin!(stream);
+    ///
+    ///     while let Some(Ok(user)) = stream.next().await {
+    ///         println!("User: {:?}", user);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_statement<E>(
+        &self,
+        statement: Statement,
+        values: impl SerializeRow,
+    ) -> Result<impl Stream<Item = Result<E>>>
+    where
+        E: Model + for<'frame, 'metadata> DeserializeRow<'frame, 'metadata> + Send + 'static,
+    {
+        debug!("Stream statement: {}", statement.contents);
+
+        let rows = self
+            .session
+            .execute_iter(statement, values)
+            .await?
+            .rows_stream::<E>()?;
+
+        Ok(rows.map_err(Error::from))
+    }
+
+    /// Creates a stream mapping raw rows with a closure
+    ///
+    /// Unlike `stream`, this method doesn't deserialize rows into a whole model. Each
+    /// row is passed as an untyped driver `Row` to `f`, which maps it into a lightweight
+    /// type. This is useful for projections selecting only a few columns. Rows are
+    /// fetched page by page as the stream is consumed.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type each row is mapped into
+    /// * `F` - The closure mapping a row
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A CQL `SELECT` query
+    /// * `values` - Values to bind to the query parameters
+    /// * `f` - The closure mapping each row; an error is yielded by the stream
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a stream of mapped rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::value::CqlValue;
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let names: Vec<String> = client
+    ///         .stream_projection("SELECT name FROM users", &[], |row| {
+    ///             let name = row.columns[0].as_ref().and_then(CqlValue::as_text);
+    ///             Ok(name.cloned().unwrap_or_default())
+    ///         })
+    ///         .await?
+    ///         .try_collect()
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_projection<T, F>(
+        &self,
+        query: &str,
+        values: impl SerializeRow,
+        f: F,
+    ) -> Result<impl Stream<Item = Result<T>>>
+    where
+        F: Fn(Row) -> Result<T>,
+    {
+        debug!("Stream projection query: {}", query);
+
+        let rows = self
+            .session
+            .execute_iter(query, values)
+            .await?
+            .rows_stream::<Row>()?;
+
+        Ok(rows.map(move |row| f(row?)))
+    }
+
+    /// Exports the results of a query as CSV
+    ///
+    /// Rows are streamed and written one line at a time, so the export doesn't
+    /// materialize all rows in memory. Each row is converted into its fields by
+    /// `row_fn`. Fields containing commas, double quotes or line breaks are
+    /// quoted, with double quotes doubled (RFC 4180). Lines end with `\n`, and
+    /// the writer is flushed when the export completes.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being exported
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A Charybdis query configured to return a stream of results
+    /// * `writer` - The destination of the CSV, e.g. a file
+    /// * `header` - The names of the columns; an empty header writes no header line
+    /// * `row_fn` - A function converting an entity into its fields
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of exported rows, excluding the header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::operations::Find;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// #     name: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let file = tokio::fs::File::create("users.csv").await?;
+    ///     let rows = client
+    ///         .export_csv(User::find_all(), file, &["id", "name"], |user: &User| {
+    ///             vec![user.id.clone(), user.name.clone()]
+    ///         })
+    ///         .await?;
+    ///     println!("Exported {} users", rows);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn export_csv<'a, Val, E, W, F>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, ModelStream>,
+        mut writer: W,
+        header: &[&str],
+        row_fn: F,
+    ) -> Result<usize>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send + 'static,
+        W: AsyncWrite + Unpin,
+        F: Fn(&E) -> Vec<String>,
+    {
+        if !header.is_empty() {
+            writer.write_all(Self::csv_line(header).as_bytes()).await?;
+        }
+
+        let mut stream = self.stream(query).await?;
+        let mut rows = 0;
+
+        while let Some(entity) = stream.next().await {
+            writer
+                .write_all(Self::csv_line(&row_fn(&entity?)).as_bytes())
+                .await?;
+            rows += 1;
+        }
+
+        writer.flush().await?;
+
+        Ok(rows)
+    }
+}
+
+// ================================================================================================
+// Table Management
+// ================================================================================================
+impl Client {
+    /// Drops a table from the database if it exists
+    ///
+    /// This method executes a `DROP TABLE IF EXISTS` statement for the specified
+    /// table name. It's safe to call even if the table doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the table to drop
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the drop operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.drop_table("old_users_table").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn drop_table(&self, name: &str) -> Result<()> {
+        let query = format!("DROP TABLE IF EXISTS {name};");
+
+        self.execute_schema(&query).await?;
+
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// Keyspace Management
+// ================================================================================================
+impl Client {
+    /// Retrieves a list of all keyspaces in the ScyllaDB cluster
+    ///
+    /// This method queries the system schema to get a list of all available
+    /// keyspaces in the connected ScyllaDB cluster.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of keyspace names or an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let keyspaces = client.keyspaces().await?;
+    ///     for keyspace in keyspaces {
+    ///         println!("Keyspace: {}", keyspace);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn keyspaces(&self) -> Result<Vec<String>> {
+        let query = "SELECT keyspace_name FROM system_schema.keyspaces;";
+
+        let res = self.session.execute_unpaged(query, &[]).await?;
+
+        let keyspaces: Vec<String> = res
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .filter_map(|s| s.ok()) // Используем filter_map для извлечения значений
+            .map(|(keyspace_name,)| keyspace_name) // Извлекаем имя keyspace
+            .collect();
+
+        Ok(keyspaces)
+    }
+
+    /// Retrieves a list of the user-defined types of a keyspace
+    ///
+    /// This method queries the system schema to get the names of all
+    /// user-defined types (UDTs) created in the given keyspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyspace` - The name of the keyspace to list the types of
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of type names or an error. A missing
+    /// keyspace has no types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     let types = client.user_types("my_keyspace").await?;
+    ///     for type_name in types {
+    ///         println!("Type: {}", type_name);
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn user_types(&self, keyspace: &str) -> Result<Vec<String>> {
+        let query = "SELECT type_name FROM system_schema.types WHERE keyspace_name = ?;";
+
+        let res = self.session.execute_unpaged(query, (keyspace,)).await?;
+
+        let types: Vec<String> = res
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .filter_map(|s| s.ok())
+            .map(|(type_name,)| type_name)
+            .collect();
+
+        Ok(types)
+    }
+
+    /// Gets the currently active keyspace for this session
+    ///
+    /// Returns the name of the keyspace that is currently being used by
+    /// the session, if any.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` containing the keyspace name, or `None` if no keyspace is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     if let Some(keyspace) = client.get_keyspace() {
+    ///         println!("Current keyspace: {}", keyspace);
+    ///     } else {
+    ///         println!("No keyspace is currently set");
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_keyspace(&self) -> Option<String> {
+        let keyspace = self.session.get_session().get_keyspace();
+
+        keyspace.map(|k| k.to_string())
+    }
+
+    /// Sets the active keyspace for this session
+    ///
+    /// Changes the current keyspace context for the session. All subsequent
+    /// queries will be executed in the context of this keyspace unless
+    /// explicitly qualified with a different keyspace name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to use
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.use_keyspace("my_application").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn use_keyspace(&self, name: &str) -> Result<()> {
+        self.session.get_session().use_keyspace(name, true).await?;
+
+        Ok(())
+    }
+
+    // Drops and recreates a keyspace
+    ///
+    /// This method first drops the specified keyspace (if it exists) and then
+    /// creates it again with default replication settings. This is useful for
+    /// resetting a keyspace to a clean state.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to recreate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::Result;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     client.recreate_keyspace("test_keyspace").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn recreate_keyspace(&self, name: &str) -> Result<()> {
+        self.drop_keyspace(name).await?;
+        self.create_keyspace(name).await?;
+
+        Ok(())
+    }
+
+    /// Recreates a keyspace and returns the client instance (builder pattern)
+    ///
+    /// This is a convenience method that combines `recreate_keyspace` with the
+    /// builder pattern, allowing you to chain method calls during client setup.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to recreate
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::connect(&ConnectionParams::default())
+    ///         .await?
+    ///         .with_recreate_keyspace("test_keyspace")
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn with_recreate_keyspace(self, name: &str) -> Result<Self> {
+        self.recreate_keyspace(name).await?;
+
+        Ok(self)
+    }
+
+    /// Drops and recreates a keyspace, then runs migrations against it
+    ///
+    /// This is a one-call schema reset, useful for test setup. The keyspace is
+    /// recreated, set as the active keyspace of the session and then migrated, so
+    /// the migrations always target the fresh keyspace. The keyspace stays active
+    /// after the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyspace` - The name of the keyspace to reset
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.migrate_fresh("test_keyspace").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn migrate_fresh(&self, keyspace: &str) -> Result<()> {
+        self.recreate_keyspace(keyspace).await?;
+        self.use_keyspace(keyspace).await?;
+
+        Self::migrate(self.session.get_session(), &Some(keyspace.to_string())).await
+    }
+
+    /// Creates a new keyspace if it doesn't already exist
+    ///
+    /// This method executes a `CREATE KEYSPACE IF NOT EXISTS` statement with
+    /// SimpleStrategy replication and a replication factor of 1. This is suitable
+    /// for development and testing environments.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to create
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.create_keyspace("my_application").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_keyspace(&self, name: &str) -> Result<()> {
+        let query = format!("CREATE KEYSPACE IF NOT EXISTS {name} WITH REPLICATION = {{ 'class' : 'SimpleStrategy', 'replication_factor' : 1 }};");
+
+        self.execute_schema(&query).await?;
+
+        Ok(())
+    }
+
+    /// Clones the schema of a keyspace into another keyspace
+    ///
+    /// This method reads user-defined types and table definitions of the `from`
+    /// keyspace from `system_schema` and recreates them in the `to` keyspace, which
+    /// is created if it doesn't exist. Only the schema is copied, not the data.
+    ///
+    /// User-defined types are created before tables, and types referenced by other
+    /// types are created first. Table options other than the clustering order,
+    /// secondary indexes and materialized views are not cloned.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The name of the keyspace to read the schema from
+    /// * `to` - The name of the keyspace to create the schema in
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.clone_schema("production", "staging").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn clone_schema(&self, from: &str, to: &str) -> Result<()> {
+        debug!("Cloning schema from '{}' to '{}'", from, to);
+
+        self.create_keyspace(to).await?;
+
+        // User-defined types must exist before tables that reference them
+        for query in self.type_definitions(from, to).await? {
+            self.execute_schema(&query).await?;
+        }
+
+        for query in self.table_definitions(from, to).await? {
+            self.execute_schema(&query).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Internal method for building `CREATE TYPE` statements of a keyspace
+    ///
+    /// Statements are ordered so that every type is created after the types it references.
+    async fn type_definitions(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let query = "SELECT type_name, field_names, field_types FROM system_schema.types WHERE keyspace_name = ?;";
+
+        let mut pending = self
+            .execute(query, (from,))
+            .await?
+            .into_rows_result()?
+            .rows::<(String, Vec<String>, Vec<String>)>()?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let references = |field_type: &str, name: &str| {
+            field_type
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .any(|token| token == name)
+        };
+
+        let mut queries = vec![];
+
+        while !pending.is_empty() {
+            // Pick a type that doesn't reference any type that is not created yet
+            let index = pending
+                .iter()
+                .position(|(name, _, field_types)| {
+                    field_types.iter().all(|field_type| {
+                        pending
+                            .iter()
+                            .all(|(other, ..)| other == name || !references(field_type, other))
+                    })
+                })
+                .unwrap_or(0);
+
+            let (name, field_names, field_types) = pending.remove(index);
+
+            let fields = field_names
+                .iter()
+                .zip(field_types.iter())
+                .map(|(field_name, field_type)| format!("{field_name} {field_type}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            queries.push(format!("CREATE TYPE IF NOT EXISTS {to}.{name} ({fields});"));
+        }
+
+        Ok(queries)
+    }
+
+    /// Internal method for building `CREATE TABLE` statements of a keyspace
+    async fn table_definitions(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let query = "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ?;";
+
+        let tables = self
+            .execute(query, (from,))
+            .await?
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .map(|row| row.map(|(table_name,)| table_name))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let query = "SELECT table_name, column_name, kind, position, type, clustering_order FROM system_schema.columns WHERE keyspace_name = ?;";
+
+        let columns = self
+            .execute(query, (from,))
+            .await?
+            .into_rows_result()?
+            .rows::<(String, String, String, i32, String, String)>()?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut queries = vec![];
+
+        for table in tables {
+            let mut definitions = vec![];
+            let mut partition_keys = vec![];
+            let mut clustering_keys = vec![];
+
+            for (table_name, column_name, kind, position, column_type, clustering_order) in &columns
+            {
+                if *table_name != table {
+                    continue;
+                }
+
+                match kind.as_str() {
+                    "partition_key" => partition_keys.push((*position, column_name.clone())),
+                    "clustering" => clustering_keys.push((
+                        *position,
+                        column_name.clone(),
+                        clustering_order.to_uppercase(),
+                    )),
+                    _ => {}
+                }
+
+                if kind == "static" {
+                    definitions.push(format!("{column_name} {column_type} static"));
+                } else {
+                    definitions.push(format!("{column_name} {column_type}"));
+                }
+            }
+
+            partition_keys.sort();
+            clustering_keys.sort();
+
+            let partition_keys = partition_keys
+                .into_iter()
+                .map(|(_, name)| name)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let mut primary_key = format!("({partition_keys})");
+            for (_, name, _) in &clustering_keys {
+                primary_key.push_str(&format!(", {name}"));
+            }
+
+            let mut query = format!(
+                "CREATE TABLE IF NOT EXISTS {to}.{table} ({}, PRIMARY KEY ({primary_key}))",
+                definitions.join(", ")
+            );
+
+            if !clustering_keys.is_empty() {
+                let order = clustering_keys
+                    .iter()
+                    .map(|(_, name, order)| format!("{name} {order}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                query.push_str(&format!(" WITH CLUSTERING ORDER BY ({order})"));
+            }
+
+            query.push(';');
+            queries.push(query);
+        }
+
+        Ok(queries)
+    }
+
+    /// Drops a keyspace if it exists
+    ///
+    /// This method executes a `DROP KEYSPACE IF EXISTS` statement for the
+    /// specified keyspace. It's safe to call even if the keyspace doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to drop
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.drop_keyspace("old_keyspace").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn drop_keyspace(&self, name: &str) -> Result<()> {
+        let query = format!("DROP KEYSPACE IF EXISTS {name};");
+
+        self.execute_schema(&query).await?;
+
+        Ok(())
+    }
+
+    /// Creates a keyspace and returns the client instance (builder pattern)
+    ///
+    /// This is a convenience method that combines `create_keyspace` with the
+    /// builder pattern, allowing you to chain method calls during client setup.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to create
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::connect(&ConnectionParams::default())
+    ///         .await?
+    ///         .with_keyspace("my_application")
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn with_keyspace(self, name: &str) -> Result<Self> {
+        self.create_keyspace(name).await?;
+
+        Ok(self)
+    }
+
+    /// Creates multiple keyspaces and returns the client instance (builder pattern)
+    ///
+    /// This method creates multiple keyspaces in sequence and returns the client
+    /// instance for method chaining. Useful when setting up multiple keyspaces
+    /// during application initialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - A slice of keyspace names to create
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::connect(&ConnectionParams::default())
+    ///         .await?
+    ///         .with_keyspaces(&["users", "products", "orders"])
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn with_keyspaces(self, names: &[&str]) -> Result<Self> {
+        for name in names {
+            self.create_keyspace(name).await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Drops a keyspace and returns the client instance (builder pattern)
+    ///
+    /// This is a convenience method that combines `drop_keyspace` with the
+    /// builder pattern, allowing you to chain method calls during client setup.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the keyspace to drop
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::connect(&ConnectionParams::default())
+    ///         .await?
+    ///         .without_keyspace("old_keyspace")
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn without_keyspace(self, name: &str) -> Result<Self> {
+        self.drop_keyspace(name).await?;
+
+        Ok(self)
+    }
+
+    /// Drops multiple keyspaces and returns the client instance (builder pattern)
+    ///
+    /// This method drops multiple keyspaces in sequence and returns the client
+    /// instance for method chaining. Useful when cleaning up multiple keyspaces
+    /// during application shutdown or testing.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - A slice of keyspace names to drop
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::connect(&ConnectionParams::default())
+    ///         .await?
+    ///         .without_keyspaces(&["test_users", "test_products"])
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn without_keyspaces(self, names: &[&str]) -> Result<Self> {
+        for name in names {
+            self.drop_keyspace(name).await?;
+        }
+
+        Ok(self)
+    }
+}
+
+// ================================================================================================
+// Utility methods
+// ================================================================================================
+impl Client {
+    /// Executes a raw CQL query with the provided values
+    ///
+    /// This method provides direct access to the underlying ScyllaDB session
+    /// for executing custom CQL queries that are not covered by the high-level
+    /// CRUD operations. Use this for complex queries, DDL statements, or
+    /// database administration tasks.
+    ///
+    /// The client's CRUD parameters (consistency, serial consistency, timeout,
+    /// timestamp) are applied to the statement. Use `execute_with` to override
+    /// them for a single statement.
+    ///
+    /// Raw statements are treated as non-idempotent, so a configured retry
+    /// policy applies only if `retry_non_idempotent` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * query - The CQL query string to execute
+    /// * values - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the QueryResult or an error.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let result = client.execute("SELECT COUNT(*) FROM users WHERE active = ?",
+    ///         (true,)).await?;  
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute(&self, query: &str, values: impl SerializeRow) -> Result<QueryResult> {
+        match &self.crud_params {
+            Some(params) => self.execute_with(params, query, values).await,
+            None => {
+                debug!("Executing query: {}", query);
+
+                self.session
+                    .execute_unpaged(query, values)
+                    .await
+                    .map_err(|error| self.enrich_error(error.into()))
+            }
+        }
+    }
+
+    /// Executes a raw CQL query with the provided values and CRUD parameters
+    ///
+    /// This method works like `execute`, but applies the given CRUD parameters
+    /// instead of the client's ones, allowing per-statement overrides of the
+    /// consistency, serial consistency, timeout, timestamp and retry policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - CRUD parameters to apply to the statement
+    /// * `query` - The CQL query string to execute
+    /// * `values` - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `QueryResult` or an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, CrudParams};
+    /// use grapple_db::scylla::statement::Consistency;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let params = CrudParams {
+    ///         consistency: Consistency::All,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let result = client
+    ///         .execute_with(&params, "SELECT * FROM users WHERE id = ?", ("1",))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_with(
+        &self,
+        params: &CrudParams,
+        query: &str,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult> {
+        debug!("Executing query: {}", query);
+
+        let mut statement = Statement::new(query);
+        params.apply_statement(&mut statement);
+
+        let (statement, values) = (&statement, &values);
+        let execute = move || async move {
+            Ok(self
+                .session
+                .execute_unpaged(statement.clone(), values)
+                .await?)
+        };
+
+        // Raw statements may be non-idempotent (counters, list appends)
+        let res = match &params.retry {
+            Some(policy) => policy.run(false, execute).await,
+            None => execute().await,
+        };
+
+        res.map_err(|error| self.enrich_error(error))
+    }
+
+    /// Executes a conditional (lightweight transaction) statement
+    ///
+    /// Scylla doesn't report the number of rows affected by regular writes, but
+    /// conditional statements (`INSERT ... IF NOT EXISTS`, `UPDATE ... IF EXISTS`,
+    /// `UPDATE ... IF column = ?`, ...) return an `[applied]` column telling
+    /// whether the condition held and the write was applied. The statement is
+    /// executed with `execute`, so the client's CRUD parameters, including the
+    /// serial consistency, are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The conditional CQL statement to execute
+    /// * `values` - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the statement was applied, or `false`
+    /// if its condition didn't hold. A statement without a condition returns no
+    /// `[applied]` column and fails with an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let applied = client
+    ///         .execute_lwt("UPDATE users SET name = ? WHERE id = ? IF EXISTS", ("John", "1"))
+    ///         .await?;
+    ///
+    ///     if !applied {
+    ///         println!("User doesn't exist");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_lwt(&self, query: &str, values: impl SerializeRow) -> Result<bool> {
+        // The first column is `[applied]`, followed by the current values if not applied
+        let row = self
+            .execute(query, values)
+            .await?
+            .into_rows_result()?
+            .rows::<Row>()?
+            .next()
+            .transpose()?;
+
+        Ok(row
+            .and_then(|row| row.columns.into_iter().next().flatten())
+            .and_then(|applied| applied.as_boolean())
+            .unwrap_or(false))
+    }
+
+    /// Executes a pre-built statement with the provided values
+    ///
+    /// Unlike `execute`, the statement is executed as configured by the caller, so a
+    /// reusable `Statement` can carry its own consistency, page size, timeout and
+    /// idempotency flag. The client's CRUD parameters are not applied, except for the
+    /// retry policy: statements marked with `set_is_idempotent(true)` are retried on
+    /// transient errors, others only if `retry_non_idempotent` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement to execute
+    /// * `values` - Values to bind to the statement parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `QueryResult` or an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    /// use grapple_db::scylla::statement::Consistency;
+    /// use grapple_db::scylla::statement::unprepared::Statement;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let mut statement = Statement::new("UPDATE users SET name = ? WHERE id = ?");
+    ///     statement.set_is_idempotent(true);
+    ///     statement.set_consistency(Consistency::Quorum);
+    ///
+    ///     client.execute_statement(&statement, ("John", "1")).await?;
+    ///     client.execute_statement(&statement, ("Jane", "2")).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_statement(
+        &self,
+        statement: &Statement,
+        values: impl SerializeRow,
+    ) -> Result<QueryResult> {
+        debug!("Executing statement: {}", statement.contents);
+
+        let values = &values;
+        let execute = move || async move {
+            Ok(self
+                .session
+                .execute_unpaged(statement.clone(), values)
+                .await?)
+        };
+
+        let res = match self
+            .crud_params
+            .as_ref()
+            .and_then(|params| params.retry.as_ref())
+        {
+            Some(policy) => policy.run(statement.get_is_idempotent(), execute).await,
+            None => execute().await,
+        };
+
+        res.map_err(|error| self.enrich_error(error))
+    }
+
+    /// Executes a raw CQL query and deserializes the returned rows into entities
+    ///
+    /// This method is useful for custom `SELECT` queries that still map to a model,
+    /// e.g. hand-tuned queries that are not covered by the generated find functions.
+    /// Rows are fetched page by page and deserialized directly into the model type.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `E` - The entity/model type the rows are deserialized into
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - A CQL `SELECT` query returning rows of the model's table
+    /// * `values` - Values to bind to the query parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of entities. If the returned columns don't match
+    /// the model, `Error::TypeCheck` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// // Assuming you have a `User` model defined with `Charybdis`
+    /// # #[grapple_db::scylla::macros::charybdis_model(
+    /// #       table_name = users,
+    /// #       partition_keys = [id],
+    /// #       clustering_keys = [],
+    /// #   )]
+    /// # #[derive(Debug, Default)]
+    /// # struct User {
+    /// #     id: String,
+    /// # }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     let users: Vec<User> = client
+    ///         .query_as("SELECT * FROM users WHERE id IN ?", (vec!["1", "2"],))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn query_as<E>(&self, query: &str, values: impl SerializeRow) -> Result<Vec<E>>
+    where
+        E: Model + for<'frame, 'metadata> DeserializeRow<'frame, 'metadata> + Send + 'static,
+    {
+        debug!("Query as: {}", query);
+
+        let entities = self
+            .stream_statement(Statement::new(query), values)
+            .await?
+            .try_collect()
+            .await?;
+
+        Ok(entities)
+    }
+
+    /// Executes raw CQL statements as a single logged batch
+    ///
+    /// This method assembles the statements into a driver `Batch` and executes it in
+    /// one round-trip, applying the client's CRUD parameters (consistency, timestamp).
+    /// Use it for multiple raw DML statements that must be applied together.
+    ///
+    /// Counter updates can't be mixed with regular statements in one batch; in that case
+    /// the error returned by the database is propagated.
+    ///
+    /// # Arguments
+    ///
+    /// * `statements` - Pairs of CQL statements and values to bind to their parameters
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `QueryResult` of the batch or an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///
+    ///     client
+    ///         .execute_batch(vec![
+    ///             ("INSERT INTO users (id, name) VALUES (?, ?)", ("1", "John")),
+    ///             ("INSERT INTO users (id, name) VALUES (?, ?)", ("2", "Jane")),
+    ///         ])
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_batch<Val>(&self, statements: Vec<(&str, Val)>) -> Result<QueryResult>
+    where
+        Val: SerializeRow,
+    {
+        let mut batch = Batch::new(BatchType::Logged);
+        let mut values = Vec::with_capacity(statements.len());
+
+        for (statement, value) in statements {
+            debug!("Batch statement: {}", statement);
+
+            batch.append_statement(statement);
+            values.push(value);
+        }
+
+        if let Some(params) = &self.crud_params {
+            batch.set_consistency(params.consistency);
+            batch.set_timestamp(params.timestamp);
+        }
+
+        // Raw statements may be non-idempotent (counters, list appends)
+        let (batch, values) = (&batch, &values);
+        let res = self
+            .with_retry(false, move || async move {
+                Ok(self.session.batch(batch, values).await?)
+            })
+            .await?;
+
+        Ok(res)
+    }
+
+    /// Executes CQL queries from a file
+    ///
+    /// This method reads a file containing CQL statements separated by semicolons
+    /// and executes them sequentially. This is useful for running initialization
+    /// scripts, schema migrations, or bulk data operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - Path to the file containing CQL statements
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the file execution.
+    ///
+    /// # File Format
+    ///
+    /// The file should contain CQL statements separated by semicolons:
+    /// ```sql
+    /// CREATE TABLE users (id UUID PRIMARY KEY, name TEXT);
+    /// INSERT INTO users (id, name) VALUES (uuid(), 'John Doe');
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.execute_file("database/schema.cql").await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_file(&self, filename: &str) -> Result<()> {
+        debug!("Init file '{}'", filename);
+
+        let current_path = std::env::current_dir()?;
+        let file_path = Path::new(filename);
+        let full_path = current_path.join(file_path);
+
+        let raw_queries = tokio::fs::read_to_string(full_path).await?;
+
+        let queries = raw_queries
+            .split(";")
+            .map(|query| query.trim())
+            .collect::<Vec<&str>>();
+
+        for query in queries {
+            if query.is_empty() {
+                continue;
+            }
+
+            self.execute(query, &[]).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes CQL queries from multiple files in order
+    ///
+    /// Each file is executed with `execute_file`. Execution stops at the first
+    /// failing file, and the returned error identifies which file failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `filenames` - Paths to the files containing CQL statements
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success, or an `Error::ExecuteFile` containing the
+    /// name of the failing file and the underlying error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client
+    ///         .execute_files(&["database/types.cql", "database/tables.cql"])
+    ///         .await?;
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_files(&self, filenames: &[impl AsRef<str>]) -> Result<()> {
+        for filename in filenames {
+            let filename = filename.as_ref();
+
+            self.execute_file(filename)
+                .await
+                .map_err(|error| Error::ExecuteFile {
+                    filename: filename.to_string(),
+                    error: Box::new(error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs database migrations using Charybdis migration builder
+    ///
+    /// This method executes database schema migrations using the Charybdis
+    /// migration framework. It can optionally drop and recreate the keyspace
+    /// before running migrations, which is useful for development environments.
+    ///
+    /// When a keyspace is given, applied migrations are tracked in its
+    /// `grapple_db_migrations` table together with a fingerprint of the project
+    /// sources (`src/**/*.rs`). If the fingerprint matches a completed migration,
+    /// the migration build is skipped and no DDL is run, which cuts startup time.
+    /// The tracking table is only created when a migration is recorded. A migration
+    /// that was started but not completed (e.g. the process crashed) is detected
+    /// and run again.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The ScyllaDB session to use for migrations
+    /// * `use_keyspace` - Optional keyspace name to target for migrations
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the migration process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, client::Session};
+    ///
+    /// async fn run_migrations(session: &Session) -> Result<(), Box<dyn std::error::Error>> {
+    ///     Client::migrate(session, &Some("my_keyspace".to_string())).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn migrate(session: &Session, use_keyspace: &Option<String>) -> Result<()> {
+        let tracked = match use_keyspace {
+            Some(keyspace) => Self::schema_fingerprint().map(|fp| (keyspace, fp)),
+            None => None,
+        };
+
+        if let Some((keyspace, fingerprint)) = &tracked {
+            if Self::migration_applied(session, keyspace, fingerprint).await? {
+                debug!("Schema is up to date, skipping migration");
+                return Ok(());
+            }
+
+            Self::create_migrations_table(session, keyspace).await?;
+            Self::record_migration(session, keyspace, fingerprint, false).await?;
+        }
+
+        debug!("Migration started");
+
+        let mut builder = MigrationBuilder::new();
+
+        if let Some(keyspace) = use_keyspace {
+            builder = builder.keyspace(keyspace.to_owned());
+        }
+
+        let migration = builder.build(session).await;
+
+        migration.run().await;
+
+        if let Some((keyspace, fingerprint)) = &tracked {
+            Self::record_migration(session, keyspace, fingerprint, true).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Internal method for checking whether a migration was already applied
+    ///
+    /// Checks whether a completed migration with the given fingerprint is recorded.
+    /// A missing tracking table is looked up in `system_schema` and reported as not
+    /// applied, without creating it.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The ScyllaDB session to use
+    /// * `keyspace` - The keyspace holding the tracking table
+    /// * `fingerprint` - The fingerprint of the current schema sources
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if the migration is applied and completed.
+    async fn migration_applied(
+        session: &Session,
+        keyspace: &str,
+        fingerprint: &str,
+    ) -> Result<bool> {
+        let table_exists = session
+            .query_unpaged(
+                "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ? AND table_name = ?;",
+                (keyspace, MIGRATIONS_TABLE),
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .next()
+            .transpose()?
+            .is_some();
+
+        if !table_exists {
+            return Ok(false);
+        }
+
+        let applied = session
+            .query_unpaged(
+                format!(
+                    "SELECT fingerprint, completed FROM {keyspace}.{MIGRATIONS_TABLE} WHERE id = 'schema';"
+                ),
+                &[],
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<(Option<String>, Option<bool>)>()?
+            .next()
+            .transpose()?
+            .is_some_and(|(applied, completed)| {
+                applied.as_deref() == Some(fingerprint) && completed == Some(true)
+            });
+
+        Ok(applied)
+    }
+
+    /// Internal method for creating the migrations tracking table if needed
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The ScyllaDB session to use
+    /// * `keyspace` - The keyspace holding the tracking table
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    async fn create_migrations_table(session: &Session, keyspace: &str) -> Result<()> {
+        session
+            .query_unpaged(
+                format!("CREATE TABLE IF NOT EXISTS {keyspace}.{MIGRATIONS_TABLE} (id text PRIMARY KEY, fingerprint text, completed boolean, applied_at timestamp);"),
+                &[],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method for recording the state of a migration
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The ScyllaDB session to use
+    /// * `keyspace` - The keyspace holding the tracking table
+    /// * `fingerprint` - The fingerprint of the current schema sources
+    /// * `completed` - Whether the migration has completed
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure of the operation.
+    async fn record_migration(
+        session: &Session,
+        keyspace: &str,
+        fingerprint: &str,
+        completed: bool,
+    ) -> Result<()> {
+        session
+            .query_unpaged(
+                format!("INSERT INTO {keyspace}.{MIGRATIONS_TABLE} (id, fingerprint, completed, applied_at) VALUES ('schema', ?, ?, toTimestamp(now()));"),
+                (fingerprint, completed),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method for fingerprinting the sources the migrations are built from
+    ///
+    /// Hashes the paths and contents of all `.rs` files under `src` of the current
+    /// directory, which is where Charybdis looks for models. A changed fingerprint
+    /// (including one caused by a different toolchain) only results in the migration
+    /// being run again.
+    ///
+    /// # Returns
+    ///
+    /// The fingerprint, or `None` if the sources can't be read.
+    fn schema_fingerprint() -> Option<String> {
+        fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+
+                if path.is_dir() {
+                    collect(&path, files)?;
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    files.push(path);
+                }
+            }
+
+            Ok(())
+        }
+
+        let root = std::env::current_dir().ok()?.join("src");
+
+        let mut files = vec![];
+        collect(&root, &mut files).ok()?;
+        files.sort();
+
+        let mut hasher = DefaultHasher::new();
+
+        for file in files {
+            file.strip_prefix(&root).ok()?.hash(&mut hasher);
+            std::fs::read(&file).ok()?.hash(&mut hasher);
+        }
+
+        Some(format!("{:016x}", hasher.finish()))
+    }
+
+    /// Checks whether all nodes in the cluster agree on the schema version
+    ///
+    /// After migrations or DDL statements, nodes may temporarily report different
+    /// schema versions. Use this method to confirm that the schema change has
+    /// propagated before issuing dependent queries. A single-node cluster always agrees.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing `true` if all reachable nodes report the same schema
+    /// version, or `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::default().await?;
+    ///     
+    ///     client.execute("CREATE TABLE IF NOT EXISTS t (id int PRIMARY KEY);", &[]).await?;
+    ///
+    ///     if client.schema_agreement().await? {
+    ///         println!("Schema is in agreement");
+    ///     }
+    ///     
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn schema_agreement(&self) -> Result<bool> {
+        let version = self.session.get_session().check_schema_agreement().await?;
+
+        debug!("Schema version: {:?}", version);
+
+        Ok(version.is_some())
+    }
+
+    /// Internal method for wrapping the error of a failed batch chunk
+    ///
+    /// Chunks are applied sequentially, so the index of the failing chunk is also
+    /// the number of chunks applied before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk` - The index of the failing chunk
+    /// * `error` - The error the chunk failed with
+    ///
+    /// # Returns
+    ///
+    /// An `Error::BatchChunk` wrapping the error
+    fn batch_chunk_error(chunk: usize, error: impl Into<Error>) -> Error {
+        Error::BatchChunk {
+            chunk,
+            error: Box::new(error.into()),
+        }
+    }
+
+    /// Internal method for logging the shape of a chunked batch operation
+    ///
+    /// Reports the total number of items, the chunk size and the resulting number
+    /// of chunks, which helps to diagnose batch-too-large errors. A chunk size of 0
+    /// is reported as 1, matching how the items are chunked.
+    fn log_batch(operation: &str, batch_type: BatchType, items: usize, chunk_size: usize) {
+        let chunk_size = chunk_size.max(1);
+        let chunks = items.div_ceil(chunk_size);
+
+        debug!(
+            "{} batch ({:?}): {} items, chunk size {}, {} chunks",
+            operation, batch_type, items, chunk_size, chunks
+        );
+    }
+
+    /// Internal method for qualifying the table of a query with a keyspace
+    ///
+    /// The table following the first `FROM` keyword is prefixed with the keyspace.
+    /// Queries without a `FROM` clause or with an already qualified table are
+    /// returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The CQL query to qualify
+    /// * `keyspace` - The keyspace to qualify the table with
+    ///
+    /// # Returns
+    ///
+    /// The query with the qualified table.
+    fn qualify_table(query: &str, keyspace: &str) -> String {
+        // ASCII uppercase keeps byte offsets valid for the original query
+        let Some(pos) = query.to_ascii_uppercase().find(" FROM ") else {
+            return query.to_string();
+        };
+
+        let rest = &query[pos + " FROM ".len()..];
+        let start = query.len() - rest.trim_start().len();
+        let end = query[start..]
+            .find(|c: char| c.is_whitespace() || c == ';')
+            .map_or(query.len(), |len| start + len);
+
+        if query[start..end].contains('.') {
+            return query.to_string();
+        }
+
+        format!(
+            "{}{}.{}{}",
+            &query[..start],
+            keyspace,
+            &query[start..end],
+            &query[end..]
+        )
+    }
+
+    /// Internal method for replacing the selection of a query with `COUNT(*)`
+    ///
+    /// Queries without a `FROM` clause are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The CQL `SELECT` query to rewrite
+    ///
+    /// # Returns
+    ///
+    /// The query selecting only the number of matching rows.
+    fn count_query(query: &str) -> String {
+        // ASCII uppercase keeps byte offsets valid for the original query
+        match query.to_ascii_uppercase().find(" FROM ") {
+            Some(pos) => format!("SELECT COUNT(*){}", &query[pos..]),
+            None => query.to_string(),
+        }
+    }
+
+    /// Internal method for building a `COUNT(*)` statement from a `SELECT` query
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The CQL `SELECT` query to count the rows of
+    /// * `params` - CRUD parameters to apply to the statement, if any
+    ///
+    /// # Returns
+    ///
+    /// An idempotent statement counting the rows matching the query.
+    fn count_statement(query: &str, params: Option<&CrudParams>) -> Statement {
+        let mut statement = Statement::new(Self::count_query(query));
+        statement.set_is_idempotent(true);
+
+        if let Some(params) = params {
+            params.apply_statement(&mut statement);
+        }
+
+        statement
+    }
+
+    /// Internal method for executing a `COUNT(*)` statement and reading the count
+    async fn count_with_statement(
+        &self,
+        statement: &Statement,
+        values: impl SerializeRow,
+    ) -> Result<usize> {
+        let count = self
+            .execute_statement(statement, values)
+            .await?
+            .into_rows_result()?
+            .rows::<(i64,)>()?
+            .next()
+            .transpose()?
+            .map_or(0, |(count,)| count);
+
+        Ok(count as usize)
+    }
+
+    /// Internal method for formatting a line of CSV
+    ///
+    /// Fields containing commas, double quotes or line breaks are quoted,
+    /// and double quotes inside them are doubled.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The fields of the line
+    ///
+    /// # Returns
+    ///
+    /// The CSV line, terminated with `\n`.
+    fn csv_line<S: AsRef<str>>(fields: &[S]) -> String {
+        let mut line = fields
+            .iter()
+            .map(|field| {
+                let field = field.as_ref();
+
+                if field.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", field.replace('"', "\"\""))
+                } else {
+                    field.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        line.push('\n');
+        line
+    }
+
+    /// Internal method for executing a keyspace or table management statement
+    ///
+    /// Uses the schema consistency when one is set, and falls back to `execute`
+    /// (with the CRUD parameters) otherwise.
+    async fn execute_schema(&self, query: &str) -> Result<QueryResult> {
+        match self.schema_consistency {
+            Some(consistency) => {
+                let statement = Self::schema_statement(query, consistency);
+                self.execute_statement(&statement, &[]).await
+            }
+            None => self.execute(query, &[]).await,
+        }
+    }
+
+    /// Internal method for building a schema statement with the given consistency
+    fn schema_statement(query: &str, consistency: Consistency) -> Statement {
+        let mut statement = Statement::new(query);
+        statement.set_consistency(consistency);
+        statement
+    }
+
+    /// Internal method for wrapping a shared driver session in a statement cache
+    fn caching_session(session: &Arc<Session>, capacity: usize) -> CachingSession {
+        CachingSessionBuilder::new_shared(session.clone())
+            .max_capacity(capacity)
+            .build()
+    }
+
+    /// Internal method for building a counter update from a find-by-primary-key query
+    ///
+    /// The table and the `WHERE` clause are taken from the `SELECT` query, so the
+    /// resulting update binds the same primary key values.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The CQL `SELECT` query finding a row by its primary key
+    /// * `column` - The name of the counter column
+    /// * `by` - The amount to add to the counter
+    ///
+    /// # Returns
+    ///
+    /// The counter update query.
+    fn counter_query(query: &str, column: &str, by: i64) -> String {
+        // ASCII uppercase keeps byte offsets valid for the original query
+        let upper = query.to_ascii_uppercase();
+        let from = upper.find(" FROM ").map_or(0, |pos| pos + " FROM ".len());
+        let filter = upper.find(" WHERE ").unwrap_or(query.len());
+
+        let table = query[from..filter].trim();
+        let op = if by < 0 { '-' } else { '+' };
+
+        format!(
+            "UPDATE {table} SET {column} = {column} {op} {}{}",
+            by.unsigned_abs(),
+            &query[filter..]
+        )
+    }
+
+    /// Internal method for running an operation with the configured retry policy
+    ///
+    /// If a retry policy is set in the client's CRUD parameters, the operation is
+    /// retried on transient errors according to it. Otherwise it runs once.
+    ///
+    /// # Arguments
+    ///
+    /// * `idempotent` - Whether the operation is safe to repeat
+    /// * `operation` - A closure creating the operation future for every attempt
+    ///
+    /// # Returns
+    ///
+    /// The result of the operation.
+    async fn with_retry<T, F, Fut>(&self, idempotent: bool, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let res = match self.crud_params.as_ref().and_then(|p| p.retry.as_ref()) {
+            Some(policy) => policy.run(idempotent, operation).await,
+            None => operation().await,
+        };
+
+        res.map_err(|error| self.enrich_error(error))
+    }
+
+    /// Internal method for making errors caused by a missing keyspace clearer
+    ///
+    /// When the session has no keyspace set, the driver reports a cryptic
+    /// "no keyspace has been specified" error. Such errors are wrapped into
+    /// `Error::MissingKeyspace` carrying the keyspace recorded on the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error to enrich
+    ///
+    /// # Returns
+    ///
+    /// The enriched error, or the original error if it is not caused by a missing keyspace.
+    fn enrich_error(&self, error: Error) -> Error {
+        let message = format!("{error:?}").to_ascii_lowercase();
+
+        if message.contains("no keyspace has been specified") {
+            Error::MissingKeyspace {
+                expected: self.keyspace.clone(),
+                error: Box::new(error),
+            }
+        } else {
+            error
+        }
+    }
+
+    /// Internal method for applying CRUD parameters to batch operations
+    ///
+    /// This method applies the client's CRUD parameters (consistency, timeout,
+    /// timestamp) to a Charybdis model batch if parameters are configured.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the batch
+    /// * `E` - The entity/model type being batched
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - The Charybdis model batch to enhance with parameters
+    ///
+    /// # Returns
+    ///
+    /// The batch with applied CRUD parameters, or the original batch if no parameters are set.
+    fn batch_apply_params<'a, Val, E>(
+        &self,
+        batch: CharybdisModelBatch<'a, Val, E>,
+    ) -> CharybdisModelBatch<'a, Val, E>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: ModelBatch<'a>,
+    {
+        if let Some(params) = &self.crud_params {
+            params.apply_batch(batch)
+        } else {
+            batch
+        }
+    }
+
+    /// Internal method for applying CRUD parameters to queries
+    ///
+    /// This method applies the client's CRUD parameters (consistency, timeout,
+    /// timestamp) to a Charybdis query if parameters are configured.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Val` - The type of values being serialized for the query
+    /// * `E` - The entity/model type being queried
+    /// * `Qe` - The query executor type
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The Charybdis query to enhance with parameters
+    ///
+    /// # Returns
+    ///
+    /// The query with applied CRUD parameters, or the original query if no parameters are set.
+    fn query_apply_params<'a, Val, E, Qe>(
+        &self,
+        query: CharybdisQuery<'a, Val, E, Qe>,
+    ) -> CharybdisQuery<'a, Val, E, Qe>
+    where
+        Val: SerializeRow + Sync + Send,
+        E: Model + Sync + Send,
+        Qe: QueryExecutor<E>,
+    {
+        if let Some(params) = &self.crud_params {
+            params.apply_query(query)
+        } else {
+            query
+        }
+    }
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::scylla::{
+        charybdis::{
+            self,
+            macros::charybdis_model,
+            types::{Counter, Text},
+        },
+        Client, ConnectionParams, RetryPolicy,
+    };
+
+    #[charybdis_model(
+        table_name = users,
+        partition_keys = [id],
+        clustering_keys = [],
+
+        global_secondary_indexes = [name],
+        local_secondary_indexes = [],
+    )]
+    #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Tst {
+        id: Text,
+        name: Option<Text>,
+    }
+
+    impl Tst {
+        fn with_id(id: &str) -> Self {
+            Self {
+                id: id.to_string(),
+                name: None,
+            }
+        }
+
+        fn with_name(mut self, name: impl Into<String>) -> Self {
+            _ = self.name.insert(name.into());
+            self
+        }
+    }
+
+    #[charybdis_model(
+        table_name = counters,
+        partition_keys = [id],
+        clustering_keys = [],
+    )]
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct TstCounter {
+        id: Text,
+        hits: Option<Counter>,
+    }
+
+    async fn get_client() -> Client {
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some("test".into()),
+
+            ..Default::default()
+        };
+
+        let client = Client::connect(&params).await.unwrap();
+
+        client
+            .execute(
+                "
+        CREATE TABLE IF NOT EXISTS users (
+            id text PRIMARY KEY,
+            name text
+        );
+        ",
+                &[],
+            )
+            .await
+            .unwrap();
+
+        client
+            .execute("CREATE INDEX IF NOT EXISTS ON users (name);", &[])
+            .await
+            .unwrap();
+
+        client
+    }
+
+    #[test]
+    fn test_scylla_schema_statement() {
+        let statement = Client::schema_statement(
+            "DROP KEYSPACE IF EXISTS test_schema_statement;",
+            Consistency::EachQuorum,
+        );
+
+        assert_eq!(Some(Consistency::EachQuorum), statement.get_consistency());
+        assert_eq!(
+            "DROP KEYSPACE IF EXISTS test_schema_statement;",
+            statement.contents
+        );
+    }
+
+    #[test]
+    fn test_scylla_count_statement() {
+        let params = CrudParams {
+            consistency: Consistency::Quorum,
+            ..Default::default()
+        };
+
+        let statement =
+            Client::count_statement("SELECT * FROM users WHERE name = ?", Some(&params));
+
+        assert_eq!(Some(Consistency::Quorum), statement.get_consistency());
+        assert!(statement.get_is_idempotent());
+        assert_eq!(
+            "SELECT COUNT(*) FROM users WHERE name = ?",
+            statement.contents
+        );
+
+        // Without parameters the session default is used
+        let statement = Client::count_statement("SELECT * FROM users", None);
+        assert_eq!(None, statement.get_consistency());
+    }
+
+    #[test]
+    fn test_scylla_csv_line() {
+        assert_eq!("a,b\n", Client::csv_line(&["a", "b"]));
+        assert_eq!(
+            "\"Doe, John\",\"say \"\"hi\"\"\",\"two\nlines\"\n",
+            Client::csv_line(&["Doe, John", "say \"hi\"", "two\nlines"])
+        );
+        assert_eq!("\n", Client::csv_line::<&str>(&[]));
+    }
+
+    #[tokio::test]
+    async fn test_scylla_raw_session() -> Result<()> {
+        let client = get_client().await;
+
+        // Test
+        let cluster_state = client.raw_session().get_cluster_state();
+        assert!(!cluster_state.get_nodes_info().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_resize_statement_cache() -> Result<()> {
+        let mut client = get_client().await;
+
+        let id = "test_scylla_resize_statement_cache";
+
+        // Create model
+        let model = Tst::with_id(id);
+        client.insert(&model).await?;
+        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
+
+        // Test: the driver session and its active keyspace are kept
+        let session = client.raw_session() as *const Session;
+        client.resize_statement_cache(1)?;
+
+        assert_eq!(1, client.session().get_max_capacity());
+        assert!(std::ptr::eq(session, client.raw_session()));
+        assert_eq!(Some("test".to_string()), client.get_keyspace());
+
+        // Test: queries still work, also past the new capacity
+        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
+        assert!(client.exists::<Tst>((model.id.clone(),)).await?);
+
+        // Test: a client created from a cached session can't resize it
+        let mut shared = Client::from_session(&client.session())?;
+        assert!(matches!(
+            shared.resize_statement_cache(10),
+            Err(Error::StatementCacheNotResizable)
+        ));
+
+        // Clear
+        client.delete(&model).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_nodes() -> Result<()> {
+        let client = get_client().await;
+
+        // Test
+        let nodes = client.nodes()?;
+        assert!(!nodes.is_empty());
+        assert!(nodes.iter().any(|node| node.is_up));
+        assert!(nodes.iter().all(|node| node.datacenter.is_some()));
+        assert!(nodes.iter().any(|node| node.address.port() == 9042));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_missing_keyspace_error() -> Result<()> {
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: None,
+
+            ..Default::default()
+        };
+
+        let client = Client::connect(&params)
+            .await?
+            .with_default_keyspace("test");
+
+        // Test
+        let err = client
+            .execute("SELECT * FROM users;", &[])
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::MissingKeyspace { expected: Some(keyspace), .. } if keyspace == "test")
+        );
+        assert!(err.to_string().starts_with(
+            "No keyspace is set on the session (expected keyspace 'test'), call `use_keyspace` or qualify the table name: "
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_connect_without_active_keyspace() -> Result<()> {
+        let keyspace = "test_connect_without_active_keyspace";
+
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some(keyspace.into()),
+            set_active_keyspace: false,
+
+            ..Default::default()
+        };
+
+        let client = Client::connect(&params).await?;
+
+        // Test: keyspace is created but not active
+        assert_eq!(None, client.get_keyspace());
+
+        let keyspaces = client.keyspaces().await?;
+        assert!(keyspaces.contains(&keyspace.to_string()));
+
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_connect_on_connect() -> Result<()> {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let hook_calls = calls.clone();
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some("test".into()),
+
+            ..Default::default()
+        }
+        .with_on_connect(move |session| {
+            let calls = hook_calls.clone();
+
+            Box::pin(async move {
+                session
+                    .query_unpaged("SELECT release_version FROM system.local", &[])
+                    .await?;
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                Ok(())
+            })
+        });
+
+        // Test: hook runs exactly once
+        Client::connect(&params).await?;
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+
+        // Test: an error in the hook aborts connect
+        let params = params.with_on_connect(|_| {
+            Box::pin(async { Err(Error::NotFound("on_connect".to_string())) })
+        });
+
+        let err = Client::connect(&params).await.unwrap_err();
+        assert!(matches!(err, Error::NotFound(name) if name == "on_connect"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_connect_prepare_statements() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
+
+        let query = "SELECT * FROM users WHERE id = ?";
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some("test".into()),
+            prepare_statements: vec![query.to_string()],
+
+            ..Default::default()
+        };
+
+        // Test: statements are prepared at connect and served from the cache
+        let client = Client::connect(&params).await?;
+        assert!(logs
+            .contents()
+            .contains(&format!("Preparing statement: {query}")));
+
+        let prepared = client
+            .session()
+            .add_prepared_statement(&Statement::new(query))
+            .await?;
+        assert_eq!(query, prepared.get_statement());
+
+        client
+            .execute(query, ("test_scylla_connect_prepare_statements",))
+            .await?;
+
+        // Test: a malformed statement aborts connect and names the query
+        let bad_query = "SELECT * FROM missing_table_for_prepare WHERE id = ?";
+        let params = ConnectionParams {
+            prepare_statements: vec![query.to_string(), bad_query.to_string()],
+            ..params
+        };
+
+        let err = Client::connect(&params).await.unwrap_err();
+        assert!(matches!(err, Error::PrepareStatement { query, .. } if query == bad_query));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_connect_missing_keyspace() -> Result<()> {
+        let keyspace = "test_connect_missing_keyspace";
+
+        get_client().await.drop_keyspace(keyspace).await?;
+
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some(keyspace.into()),
+            create_missing_keyspace: false,
+
+            ..Default::default()
+        };
+
+        // Test
+        let err = Client::connect(&params).await.unwrap_err();
+        assert!(matches!(err, Error::KeyspaceNotFound(name) if name == keyspace));
+
+        let keyspaces = get_client().await.keyspaces().await?;
+        assert!(!keyspaces.contains(&keyspace.to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_init_files_before_keyspace() -> Result<()> {
+        let keyspace = "test_init_files_before";
+
+        // Init file creates the keyspace with non-default options
+        let filename = std::env::temp_dir().join("test_init_files_before_keyspace.cql");
+        tokio::fs::write(
+            &filename,
+            format!(
+                "CREATE KEYSPACE IF NOT EXISTS {keyspace} WITH REPLICATION = {{ 'class' : 'SimpleStrategy', 'replication_factor' : 1 }} AND durable_writes = false;"
+            ),
+        )
+        .await?;
+
+        get_client().await.drop_keyspace(keyspace).await?;
+
+        let params = ConnectionParams {
+            migrate: false,
+            use_keyspace: Some(keyspace.into()),
+            init_files: vec![filename.to_string_lossy().to_string()],
+            init_files_before_keyspace: true,
+
+            ..Default::default()
+        };
+
+        let client = Client::connect(&params).await?;
+
+        // Test: keyspace was created by the init file, not by keyspace setup
+        let (durable_writes,) = client
+            .execute(
+                "SELECT durable_writes FROM system_schema.keyspaces WHERE keyspace_name = ?;",
+                (keyspace,),
+            )
+            .await?
+            .into_rows_result()?
+            .first_row::<(bool,)>()?;
+        assert!(!durable_writes);
+        assert_eq!(Some(keyspace.to_string()), client.get_keyspace());
+
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+        tokio::fs::remove_file(&filename).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_execute_files() -> Result<()> {
+        let client = get_client().await;
+
+        let dir = std::env::temp_dir();
+        let file1 = dir.join("test_scylla_execute_files1.cql");
+        let file2 = dir.join("test_scylla_execute_files2.cql");
+        let file3 = dir.join("test_scylla_execute_files3.cql");
+
+        tokio::fs::write(
+            &file1,
+            "CREATE TABLE IF NOT EXISTS test_execute_files (id text PRIMARY KEY);",
+        )
+        .await?;
+        tokio::fs::write(
+            &file2,
+            "INSERT INTO test_execute_files (id) VALUES ('a'); INSERT INTO test_execute_files (id) VALUES ('b');",
+        )
+        .await?;
+        tokio::fs::write(&file3, "THIS IS NOT CQL;").await?;
+
+        let file1 = file1.to_string_lossy().to_string();
+        let file2 = file2.to_string_lossy().to_string();
+        let file3 = file3.to_string_lossy().to_string();
+
+        // Test valid files
+        client.execute_files(&[&file1, &file2]).await?;
+
+        let (count,) = client
+            .execute("SELECT COUNT(*) FROM test_execute_files;", &[])
+            .await?
+            .into_rows_result()?
+            .first_row::<(i64,)>()?;
+        assert_eq!(2, count);
+
+        // Test failing file is reported
+        let err = client
+            .execute_files(&[&file1, &file2, &file3])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ExecuteFile { ref filename, .. } if *filename == file3));
+
+        // Clear
+        client.drop_table("test_execute_files").await?;
+        for file in [file1, file2, file3] {
+            tokio::fs::remove_file(file).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_schema_agreement() -> Result<()> {
+        let client = get_client().await;
+
+        client
+            .execute(
+                "CREATE TABLE IF NOT EXISTS test_schema_agreement (id text PRIMARY KEY);",
+                &[],
+            )
+            .await?;
+
+        // Test
+        assert!(client.schema_agreement().await?);
+
+        // Clear
+        client.drop_table("test_schema_agreement").await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_user_types() -> Result<()> {
+        let client = get_client().await;
+
+        let keyspace = "test_user_types";
+
+        client.recreate_keyspace(keyspace).await?;
+
+        // Create type
+        client
+            .execute(
+                &format!("CREATE TYPE IF NOT EXISTS {keyspace}.address (street text, city text);"),
+                &[],
+            )
+            .await?;
+
+        // Test
+        let types = client.user_types(keyspace).await?;
+        assert_eq!(vec!["address".to_string()], types);
+
+        assert!(client
+            .user_types("test_user_types_missing")
+            .await?
+            .is_empty());
+
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_clone_schema() -> Result<()> {
+        let client = get_client().await;
+
+        let from = "test_clone_schema_from";
+        let to = "test_clone_schema_to";
+
+        client.drop_keyspace(to).await?;
+        client.recreate_keyspace(from).await?;
+
+        // Create source schema
+        client
+            .execute(
+                &format!("CREATE TYPE IF NOT EXISTS {from}.address (street text, city text);"),
+                &[],
+            )
+            .await?;
+        client
+            .execute(
+                &format!("CREATE TABLE IF NOT EXISTS {from}.people (id text, created int, name text, address frozen<address>, PRIMARY KEY ((id), created)) WITH CLUSTERING ORDER BY (created DESC);"),
+                &[],
+            )
+            .await?;
+
+        // Test
+        client.clone_schema(from, to).await?;
+
+        let (table_name,) = client
+            .execute(
+                "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ?;",
+                (to,),
+            )
+            .await?
+            .into_rows_result()?
+            .first_row::<(String,)>()?;
+        assert_eq!("people", table_name);
+
+        client
+            .execute(
+                &format!("INSERT INTO {to}.people (id, created, name, address) VALUES ('1', 1, 'John', {{ street: 'Main', city: 'Town' }});"),
+                &[],
+            )
+            .await?;
+
+        // Clear
+        client.drop_keyspace(from).await?;
+        client.drop_keyspace(to).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_migrate_fresh() -> Result<()> {
+        let client = get_client().await;
+
+        let keyspace = "test_migrate_fresh";
+
+        client.recreate_keyspace(keyspace).await?;
+        client
+            .execute(
+                &format!("CREATE TABLE IF NOT EXISTS {keyspace}.stale (id text PRIMARY KEY);"),
+                &[],
+            )
+            .await?;
+
+        // Test
+        client.migrate_fresh(keyspace).await?;
+
+        assert_eq!(Some(keyspace.to_string()), client.get_keyspace());
+
+        let tables: Vec<String> = client
+            .execute(
+                "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ?;",
+                (keyspace,),
+            )
+            .await?
+            .into_rows_result()?
+            .rows::<(String,)>()?
+            .map(|row| row.map(|(name,)| name))
+            .collect::<std::result::Result<_, _>>()?;
+        assert!(tables.contains(&"users".to_string()));
+        assert!(!tables.contains(&"stale".to_string()));
+
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_migrate_tracking() -> Result<()> {
+        let client = get_client().await;
+
+        let keyspace = "test_migrate_tracking";
+        let use_keyspace = Some(keyspace.to_string());
+        let session = client.session();
+
+        let tables = || async {
+            client
+                .execute(
+                    "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ?;",
+                    (keyspace,),
+                )
+                .await?
+                .into_rows_result()?
+                .rows::<(String,)>()?
+                .map(|row| row.map(|(name,)| name))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(Error::from)
+        };
+
+        client.recreate_keyspace(keyspace).await?;
+
+        // A user table named `migrations` doesn't collide with the tracking table
+        client
+            .execute(
+                &format!("CREATE TABLE {keyspace}.migrations (name text PRIMARY KEY);"),
+                &[],
+            )
+            .await?;
+
+        // Test: checking a keyspace without tracking table doesn't create it
+        let fingerprint = Client::schema_fingerprint().unwrap();
+        assert!(!Client::migration_applied(session.get_session(), keyspace, &fingerprint).await?);
+        assert!(!tables().await?.contains(&MIGRATIONS_TABLE.to_string()));
+
+        Client::migrate(session.get_session(), &use_keyspace).await?;
+        assert!(tables().await?.contains(&MIGRATIONS_TABLE.to_string()));
+        assert!(Client::migration_applied(session.get_session(), keyspace, &fingerprint).await?);
+
+        // Test: schema is current, nothing is run
+        {
+            let (logs, _guard) = crate::test_utils::capture_logs();
+
+            Client::migrate(session.get_session(), &use_keyspace).await?;
+
+            assert!(logs.contents().contains("skipping migration"));
+            assert!(!logs.contents().contains("Migration started"));
+        }
+
+        // Test: partially applied migration is completed
+        client
+            .execute(
+                &format!("UPDATE {keyspace}.{MIGRATIONS_TABLE} SET completed = false WHERE id = 'schema';"),
+                &[],
+            )
+            .await?;
+
+        {
+            let (logs, _guard) = crate::test_utils::capture_logs();
+
+            Client::migrate(session.get_session(), &use_keyspace).await?;
+
+            assert!(logs.contents().contains("Migration started"));
+        }
+
+        let (completed,) = client
+            .execute(
+                &format!(
+                    "SELECT completed FROM {keyspace}.{MIGRATIONS_TABLE} WHERE id = 'schema';"
+                ),
+                &[],
+            )
+            .await?
+            .into_rows_result()?
+            .first_row::<(bool,)>()?;
+        assert!(completed);
+
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_get() -> Result<()> {
+        let client = get_client().await;
+
+        let id = "test_scylla_get";
+
+        // Create model
+        let model = Tst::with_id(id);
+        client.insert(&model).await?;
+
+        // Test
+        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
+
+        // Clear
+
+        client.delete(&model).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_get_optional() -> Result<()> {
+        let client = get_client().await;
+
+        let id = "test_scylla_maybe_get";
+
+        assert!(client
+            .get_optional(Tst::maybe_find_first_by_id(id.into()))
+            .await?
+            .is_none());
+
+        // Create model
+        let model = Tst::with_id(id);
+        client.insert(&model).await?;
+
+        assert!(client
+            .get_optional(Tst::maybe_find_first_by_id(id.into()))
+            .await?
+            .is_some());
+
+        // Clear
+        client.delete(&model).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_get_many() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_get_many";
+
+        let ids = vec![
+            "test_scylla_get_many1",
+            "test_scylla_get_many2",
+            "test_scylla_get_many3",
+        ];
+
+        let models = ids
+            .iter()
+            .map(|id| Tst::with_id(id).with_name(fx_name))
+            .collect::<Vec<Tst>>();
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test
+        let queries = ids
+            .iter()
+            .map(|id| Tst::find_by_id(id.to_string()))
+            .collect::<Vec<_>>();
+        let mut got = client.get_many(queries).await?;
+        got.sort();
+
+        assert_eq!(models[0], got[0]);
+        assert_eq!(models[1], got[1]);
+        assert_eq!(models[2], got[2]);
+
+        // Clear
+        client.delete_many(&got, 3).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_first() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_first";
+
+        assert!(client
+            .first(Tst::find_by_name(fx_name.to_string()))
+            .await?
+            .is_none());
+
+        let models = [
+            Tst::with_id("test_scylla_first1").with_name(fx_name),
+            Tst::with_id("test_scylla_first2").with_name(fx_name),
+            Tst::with_id("test_scylla_first3").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test
+        let first = client
+            .first(Tst::find_by_name(fx_name.to_string()))
+            .await?
+            .unwrap();
+        assert!(models.contains(&first));
+
+        // Clear
+        client.delete_many(&models, 3).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_reload() -> Result<()> {
+        let client = get_client().await;
+
+        let model = Tst::with_id("test_scylla_reload").with_name("test_scylla_reload");
+
+        // Create model
+        client.insert(&model).await?;
+
+        // Test
+        client
+            .execute(
+                "UPDATE users SET name = ? WHERE id = ?;",
+                ("test_scylla_reload_updated", &model.id),
+            )
+            .await?;
+
+        let reloaded = client.reload(&model).await?;
+        assert_eq!(model.id, reloaded.id);
+        assert_eq!(
+            Some("test_scylla_reload_updated".to_string()),
+            reloaded.name
+        );
+
+        // Clear
+        client.delete(&model).await?;
+
+        assert!(client.reload(&model).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_exists() -> Result<()> {
+        let client = get_client().await;
+
+        let model = Tst::with_id("test_scylla_exists").with_name("test_scylla_exists");
+
+        // Test missing row
+        client.delete(&model).await?;
+        assert!(!client.exists::<Tst>((model.id.clone(),)).await?);
+
+        // Test existing row
+        client.insert(&model).await?;
+        assert!(client.exists::<Tst>((model.id.clone(),)).await?);
+
+        // Clear
+        client.delete(&model).await?;
+
+        assert!(!client.exists::<Tst>((model.id.clone(),)).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_increment() -> Result<()> {
+        let client = get_client().await;
+
+        let id = "test_scylla_increment".to_string();
+
+        client
+            .execute(
+                "CREATE TABLE IF NOT EXISTS counters (id text PRIMARY KEY, hits counter);",
+                &[],
+            )
+            .await?;
+        client
+            .execute("DELETE FROM counters WHERE id = ?;", (&id,))
+            .await?;
+
+        // Test
+        client
+            .increment::<TstCounter>((id.clone(),), "hits", 5)
+            .await?;
+        client
+            .increment::<TstCounter>((id.clone(),), "hits", 3)
+            .await?;
+        client
+            .increment::<TstCounter>((id.clone(),), "hits", -1)
+            .await?;
+
+        let counter = client.get(TstCounter::find_by_id(id.clone())).await?;
+        assert_eq!(Some(Counter(7)), counter.hits);
+
+        // Test non-counter column
+        let res = client
+            .increment::<Tst>(("test_scylla_increment".to_string(),), "name", 1)
+            .await;
+        assert!(res.is_err());
+
+        // Test: counter updates are not retried, even with `retry_non_idempotent`
+        let retrying = client.clone().with_params(CrudParams {
+            timeout: Some(Duration::from_nanos(1)),
+            retry: Some(RetryPolicy {
+                max_retries: 2,
+                backoff: Duration::from_millis(1),
+                retry_non_idempotent: true,
+            }),
+            ..Default::default()
+        });
+
+        {
+            let (logs, _guard) = crate::test_utils::capture_logs();
+
+            // Raw statements are retried with this policy
+            let res = retrying
+                .execute("SELECT * FROM counters WHERE id = ?;", (&id,))
+                .await;
+            assert!(res.is_err());
+            assert!(logs.contents().contains("retrying"));
+        }
+
+        {
+            let (logs, _guard) = crate::test_utils::capture_logs();
+
+            let res = retrying
+                .increment::<TstCounter>((id.clone(),), "hits", 1)
+                .await;
+            assert!(res.is_err());
+            assert!(!logs.contents().contains("retrying"));
+        }
+
+        // Clear
+        client
+            .execute("DELETE FROM counters WHERE id = ?;", (&id,))
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream";
+
+        let models = [
+            Tst::with_id("test_scylla_stream1").with_name(fx_name),
+            Tst::with_id("test_scylla_stream2").with_name(fx_name),
+            Tst::with_id("test_scylla_stream3").with_name(fx_name),
+            Tst::with_id("test_scylla_stream4").with_name(fx_name),
+            Tst::with_id("test_scylla_stream5").with_name(fx_name),
+            Tst::with_id("test_scylla_stream6").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 6).await?;
+
+        // Test
+        let mut stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+
+        let mut got = vec![];
+
+        while let Some(Ok(model)) = stream.next().await {
+            got.push(model);
+        }
+
+        assert_eq!(6, got.len());
+
+        got.sort();
+
+        assert_eq!(models[0], got[0]);
+        assert_eq!(models[1], got[1]);
+        assert_eq!(models[2], got[2]);
+        assert_eq!(models[3], got[3]);
+        assert_eq!(models[4], got[4]);
+        assert_eq!(models[5], got[5]);
+
+        // Clear
+        client.delete_many(&got, 6).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_cancellable() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_cancellable";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test
+        let token = CancellationToken::new();
+        let stream = client
+            .stream_cancellable(Tst::find_by_name(fx_name.to_string()), token.clone())
+            .await?;
+        let mut stream = std::pin::pin!(stream);
+
+        assert!(stream.next().await.transpose()?.is_some());
+
+        token.cancel();
+
+        let next = tokio::time::timeout(Duration::from_secs(1), stream.next()).await?;
+        assert!(next.is_none());
+        assert!(stream.next().await.is_none());
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_with_progress() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_with_progress";
+
+        let models: Vec<Tst> = (0..5)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 5).await?;
+
+        // Test
+        let mut progress = vec![];
+        let stream = client
+            .stream_with_progress(Tst::find_by_name(fx_name.to_string()), 2, |count| {
+                progress.push(count)
+            })
+            .await?;
+        assert_eq!(5, stream.count().await);
+        assert_eq!(vec![2, 4, 5], progress);
+
+        // Test: only the final count is reported
+        let mut progress = vec![];
+        let stream = client
+            .stream_with_progress(Tst::find_by_name(fx_name.to_string()), 0, |count| {
+                progress.push(count)
+            })
+            .await?;
+        assert_eq!(5, stream.count().await);
+        assert_eq!(vec![5], progress);
+
+        // Test: the final count is not repeated when it divides evenly by `every`
+        let mut progress = vec![];
+        let stream = client
+            .stream_with_progress(Tst::find_by_name(fx_name.to_string()), 5, |count| {
+                progress.push(count)
+            })
+            .await?;
+        assert_eq!(5, stream.count().await);
+        assert_eq!(vec![5], progress);
+
+        // Test: an empty stream reports a final count of 0
+        let mut progress = vec![];
+        let stream = client
+            .stream_with_progress(
+                Tst::find_by_name(format!("{fx_name}_missing")),
+                2,
+                |count| progress.push(count),
+            )
+            .await?;
+        assert_eq!(0, stream.count().await);
+        assert_eq!(vec![0], progress);
+
+        // Clear
+        client.delete_many(&models, 5).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_buffered() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_buffered";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test
+        let mut unbuffered: Vec<Tst> = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?
+            .try_collect()
+            .await?;
+        unbuffered.sort();
+
+        for buffer in [0, 3, 100] {
+            let mut buffered: Vec<Tst> = client
+                .stream_buffered(Tst::find_by_name(fx_name.to_string()), buffer)
+                .await?
+                .try_collect()
+                .await?;
+            buffered.sort();
+
+            assert_eq!(unbuffered, buffered);
+        }
+
+        assert_eq!(10, unbuffered.len());
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_pages_exhausted() -> Result<()> {
+        use crate::scylla::stream::PagableCharybdisStream;
+        use crate::Pagable;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_pages_exhausted";
+
+        let models = [
+            Tst::with_id("test_scylla_stream_pages_exhausted1").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_pages_exhausted2").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_pages_exhausted3").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_pages_exhausted4").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 4).await?;
+
+        // Test: last page is partial
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let mut pages = PagableCharybdisStream::new(stream, 3);
+
+        assert_eq!(3, pages.next_page().await.unwrap().len());
+        assert!(!pages.is_exhausted());
+        assert_eq!(1, pages.next_page().await.unwrap().len());
+        assert!(pages.is_exhausted());
+
+        // Test: last page is exactly full
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let mut pages = PagableCharybdisStream::new(stream, 2);
+
+        assert_eq!(2, pages.next_page().await.unwrap().len());
+        assert_eq!(2, pages.next_page().await.unwrap().len());
+        assert!(!pages.is_exhausted());
+        assert!(pages.next_page().await.is_none());
+        assert!(pages.is_exhausted());
+
+        // Clear
+        client.delete_many(&models, 4).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_pages_in_memory() -> Result<()> {
+        use crate::scylla::stream::PagableCharybdisStream;
+        use crate::{InMemoryPagable, Pagable};
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_pages_in_memory";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test: both pagables page through the same dataset identically
+        let dataset: Vec<Tst> = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?
+            .try_collect()
+            .await?;
+
+        for per_page in [1, 3, 5, 10, 11] {
+            let stream = client
+                .stream(Tst::find_by_name(fx_name.to_string()))
+                .await?;
+            let mut stream_pages = PagableCharybdisStream::new(stream, per_page);
+            let mut memory_pages = InMemoryPagable::new(dataset.clone(), per_page);
+
+            stream_pages.skip_page().await;
+            memory_pages.skip_page().await;
+            assert_eq!(stream_pages.is_exhausted(), memory_pages.is_exhausted());
+
+            loop {
+                let stream_page = stream_pages.next_page_owned().await;
+                let memory_page = memory_pages.next_page_owned().await;
+
+                assert_eq!(stream_page, memory_page);
+                assert_eq!(stream_pages.is_exhausted(), memory_pages.is_exhausted());
+
+                if stream_page.is_none() {
+                    break;
+                }
+            }
+        }
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_pages_set_per_page() -> Result<()> {
+        use crate::scylla::stream::PagableCharybdisStream;
+        use crate::Pagable;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_pages_set_per_page";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let mut pages = PagableCharybdisStream::new(stream, 3);
+
+        assert_eq!(3, pages.next_page().await.unwrap().len());
+
+        pages.set_per_page(5);
+        assert_eq!(5, pages.next_page().await.unwrap().len());
+
+        // Zero-sized pages are empty and don't consume the stream
+        pages.set_per_page(0);
+        assert!(pages.next_page().await.is_none());
+        assert!(!pages.is_exhausted());
+
+        pages.set_per_page(5);
+        assert_eq!(2, pages.next_page().await.unwrap().len());
+        assert!(pages.is_exhausted());
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_paged_stream() -> Result<()> {
+        use crate::Pagable;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_paged_stream";
+
+        let models: Vec<Tst> = (0..7)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 7).await?;
+
+        // Test
+        let mut pages = client
+            .paged_stream(Tst::find_by_name(fx_name.to_string()), 3)
+            .await?;
+
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let mut manual_pages = PagableCharybdisStream::new(stream, 3);
+
+        let mut fetched = 0;
+        loop {
+            let page = pages.next_page_owned().await;
+            assert_eq!(manual_pages.next_page_owned().await, page);
+
+            match page {
+                Some(page) => fetched += page.len(),
+                None => break,
+            }
+        }
+        assert_eq!(models.len(), fetched);
+        assert_eq!(None, pages.total_items());
+
+        // Clear
+        client.delete_many(&models, 7).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_paged() -> Result<()> {
+        use crate::Pagable;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_paged";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test
+        let mut pages = client
+            .paged(|| Tst::find_by_name(fx_name.to_string()), 3)
+            .await?;
+        assert_eq!(Some(10), pages.total_items());
+        assert_eq!(Some(10_usize.div_ceil(3)), pages.total_pages());
+
+        let mut fetched = 0;
+        while let Some(page) = pages.next_page().await {
+            fetched += 1;
+            assert!(page.len() <= 3);
+        }
+        assert_eq!(pages.total_pages(), Some(fetched));
+
+        // Total pages follow the page size
+        pages.set_per_page(5);
+        assert_eq!(Some(2), pages.total_pages());
+        pages.set_per_page(0);
+        assert_eq!(Some(0), pages.total_pages());
+
+        // Streams created without a count don't know the totals
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let pages = PagableCharybdisStream::new(stream, 3);
+        assert_eq!(None, pages.total_items());
+        assert_eq!(None, pages.total_pages());
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_collect_capped() -> Result<()> {
+        use crate::scylla::stream::collect_capped;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_collect_capped";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("test_scylla_collect_capped{i}")).with_name(fx_name))
+            .collect();
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let (got, more) = collect_capped(stream, 5).await?;
+        assert_eq!(5, got.len());
+        assert!(more);
+
+        let stream = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+        let (got, more) = collect_capped(stream, 20).await?;
+        assert_eq!(10, got.len());
+        assert!(!more);
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_filtered() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_filtered";
+
+        let models = [
+            Tst::with_id("test_scylla_stream_filtered1").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_filtered2").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 2).await?;
+
+        // Test
+        let mut got = client
+            .stream_filtered::<_, Tst>("SELECT * FROM users WHERE name = ?", (fx_name,))
+            .await?
+            .try_collect()
+            .await?;
+        got.sort();
+
+        assert_eq!(models.to_vec(), got);
+        assert!(logs.contents().contains("ALLOW FILTERING"));
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_export_csv() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_export_csv, \"quoted\"";
+
+        let models = [
+            Tst::with_id("test_scylla_export_csv1").with_name(fx_name),
+            Tst::with_id("test_scylla_export_csv2").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 2).await?;
+
+        // Test
+        let mut csv = Vec::new();
+        let rows = client
+            .export_csv(
+                Tst::find_by_name(fx_name.to_string()),
+                &mut csv,
+                &["id", "name"],
+                |model: &Tst| vec![model.id.clone(), model.name.clone().unwrap_or_default()],
+            )
+            .await?;
+        assert_eq!(2, rows);
+
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines: Vec<&str> = csv.lines().collect();
+        assert_eq!("id,name", lines.remove(0));
+
+        lines.sort();
+        assert_eq!(
+            vec![
+                "test_scylla_export_csv1,\"test_scylla_export_csv, \"\"quoted\"\"\"",
+                "test_scylla_export_csv2,\"test_scylla_export_csv, \"\"quoted\"\"\"",
+            ],
+            lines
+        );
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_projection() -> Result<()> {
+        use crate::scylla::value::CqlValue;
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_stream_projection";
+
+        let models: Vec<Tst> = (0..20)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(format!("{fx_name}_name{i}")))
+            .collect();
+        let ids: Vec<String> = models.iter().map(|model| model.id.clone()).collect();
+
+        // Create models
+        client.insert_many(&models, 20).await?;
+
+        // Test
+        let mut names: Vec<String> = client
+            .stream_projection("SELECT name FROM users WHERE id IN ?", (&ids,), |row| {
+                let name = row.columns[0].as_ref().and_then(CqlValue::as_text);
+                Ok(name.cloned().unwrap_or_default())
+            })
+            .await?
+            .try_collect()
+            .await?;
+        names.sort();
+
+        let mut expected: Vec<String> = models
+            .iter()
+            .map(|model| model.name.clone().unwrap())
+            .collect();
+        expected.sort();
+        assert_eq!(expected, names);
+
+        // Clear
+        client.delete_many(&models, 20).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_count_filtered() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_count_filtered";
+
+        let models = [
+            Tst::with_id("test_scylla_count_filtered1").with_name(fx_name),
+            Tst::with_id("test_scylla_count_filtered2").with_name(fx_name),
+            Tst::with_id("test_scylla_count_filtered3").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test
+        let count = client
+            .count_filtered::<_, Tst>("SELECT * FROM users WHERE name = ?", (fx_name,))
+            .await?;
+        assert_eq!(models.len(), count);
+        assert!(logs.contents().contains("ALLOW FILTERING"));
+
+        // Clear
+        client.delete_many(&models, 3).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_count_native() -> Result<()> {
+        let client = get_client().await.with_params(CrudParams {
+            consistency: Consistency::Quorum,
+            ..Default::default()
+        });
+        let fx_name = "test_scylla_count_native";
+
+        let models = [
+            Tst::with_id("test_scylla_count_native1").with_name(fx_name),
+            Tst::with_id("test_scylla_count_native2").with_name(fx_name),
+            Tst::with_id("test_scylla_count_native3").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test
+        let query = "SELECT * FROM users WHERE name = ?";
+        assert_eq!(3, client.count_native(query, (fx_name,)).await?);
+
+        let params = CrudParams {
+            consistency: Consistency::One,
+            ..Default::default()
+        };
+        assert_eq!(
+            3,
+            client.count_native_with(query, (fx_name,), &params).await?
+        );
+
+        // Clear
+        client.delete_many(&models, 3).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_count_partitions() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_count_partitions";
+
+        let models = [
+            Tst::with_id("test_scylla_count_partitions1").with_name(fx_name),
+            Tst::with_id("test_scylla_count_partitions2").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 2).await?;
+
+        // Test
+        let keys = vec![
+            ("test_scylla_count_partitions1".to_string(),),
+            ("test_scylla_count_partitions2".to_string(),),
+            ("test_scylla_count_partitions_missing".to_string(),),
+        ];
+        assert_eq!(2, client.count_partitions::<Tst, _>(keys.clone(), 2).await?);
+        assert_eq!(2, client.count_partitions::<Tst, _>(keys, 0).await?);
+        assert_eq!(
+            0,
+            client
+                .count_partitions::<Tst, (String,)>(Vec::new(), 2)
+                .await?
+        );
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_execute_batch() -> Result<()> {
+        let client = get_client().await;
+
+        let models = [
+            Tst::with_id("test_scylla_execute_batch1").with_name("first"),
+            Tst::with_id("test_scylla_execute_batch2").with_name("second"),
+        ];
+
+        // Test
+        client
+            .execute_batch(vec![
+                (
+                    "INSERT INTO users (id, name) VALUES (?, ?)",
+                    ("test_scylla_execute_batch1", "first"),
+                ),
+                (
+                    "INSERT INTO users (id, name) VALUES (?, ?)",
+                    ("test_scylla_execute_batch2", "second"),
+                ),
+            ])
+            .await?;
+
+        assert_eq!(
+            models[0],
+            client
+                .get(Tst::find_by_id("test_scylla_execute_batch1".into()))
+                .await?
+        );
+        assert_eq!(
+            models[1],
+            client
+                .get(Tst::find_by_id("test_scylla_execute_batch2".into()))
+                .await?
+        );
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_execute_params() -> Result<()> {
+        use crate::scylla::statement::Consistency;
+
+        // Consistency THREE can't be satisfied by a single node with replication factor 1
+        let client = get_client().await.with_params(CrudParams {
+            consistency: Consistency::Three,
+            ..Default::default()
+        });
+
+        // Test
+        let query = "SELECT * FROM users WHERE id = ?";
+
+        assert!(client
+            .execute(query, ("test_scylla_execute_params",))
+            .await
+            .is_err());
+
+        let params = CrudParams {
+            consistency: Consistency::One,
+            ..Default::default()
+        };
+        client
+            .execute_with(&params, query, ("test_scylla_execute_params",))
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_execute_lwt() -> Result<()> {
+        let client = get_client().await;
+
+        let id = "test_scylla_execute_lwt";
+        let query = "UPDATE users SET name = ? WHERE id = ? IF EXISTS";
+
+        // Test: missing row is not updated
+        assert!(!client.execute_lwt(query, ("updated", id)).await?);
+        assert!(!client.exists::<Tst>((id.to_string(),)).await?);
+
+        // Test: existing row is updated
+        client.insert(&Tst::with_id(id).with_name(id)).await?;
+        assert!(client.execute_lwt(query, ("updated", id)).await?);
+
+        // Clear
+        client.delete(&Tst::with_id(id)).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_execute_statement() -> Result<()> {
+        use charybdis::scylla::statement::Consistency;
+
+        let client = get_client().await;
+
+        let model = Tst::with_id("test_scylla_execute_statement").with_name("John");
+
+        let mut statement = Statement::new("INSERT INTO users (id, name) VALUES (?, ?);");
+        statement.set_is_idempotent(true);
+        statement.set_page_size(10);
+        statement.set_consistency(Consistency::One);
+
+        // Test
+        for _ in 0..2 {
+            client
+                .execute_statement(&statement, (&model.id, &model.name))
+                .await?;
+        }
+
+        assert_eq!(model, client.reload(&model).await?);
+
+        let (count,) = client
+            .execute_statement(
+                &Statement::new("SELECT COUNT(*) FROM users WHERE id = ?;"),
+                (&model.id,),
+            )
+            .await?
+            .into_rows_result()?
+            .first_row::<(i64,)>()?;
+        assert_eq!(1, count);
+
+        // Clear
+        client.delete(&model).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_query_as() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_query_as";
+
+        let models = [
+            Tst::with_id("test_scylla_query_as1").with_name(fx_name),
+            Tst::with_id("test_scylla_query_as2").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 2).await?;
+
+        // Test
+        let mut got: Vec<Tst> = client
+            .query_as("SELECT id, name FROM users WHERE name = ?", (fx_name,))
+            .await?;
+        got.sort();
+
+        assert_eq!(models.to_vec(), got);
+
+        // Columns don't match the model
+        let err = client
+            .query_as::<Tst>("SELECT id FROM users WHERE name = ?", (fx_name,))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::TypeCheck(_)));
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_stream_in_keyspace() -> Result<()> {
+        let client = get_client().await;
+        let keyspace = "test_stream_in_keyspace";
+
+        client.recreate_keyspace(keyspace).await?;
+        client
+            .execute(
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {keyspace}.users (id text PRIMARY KEY, name text);"
+                ),
+                &[],
+            )
+            .await?;
+        client
+            .execute(
+                &format!("INSERT INTO {keyspace}.users (id, name) VALUES (?, ?);"),
+                ("test_scylla_stream_in_keyspace", "other"),
+            )
+            .await?;
+
+        // Test
+        let got = client
+            .stream_in_keyspace::<_, Tst>(
+                keyspace,
+                "SELECT * FROM users WHERE id = ?",
+                ("test_scylla_stream_in_keyspace",),
             )
-            .await
-            .unwrap();
+            .await?
+            .try_collect()
+            .await?;
 
-        client
-            .execute("CREATE INDEX IF NOT EXISTS ON users (name);", &[])
-            .await
-            .unwrap();
+        assert_eq!(
+            vec![Tst::with_id("test_scylla_stream_in_keyspace").with_name("other")],
+            got
+        );
+        assert_eq!(Some("test".to_string()), client.get_keyspace());
 
-        client
+        // Clear
+        client.drop_keyspace(keyspace).await?;
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_scylla_get() -> Result<()> {
+    async fn test_scylla_stream_statement() -> Result<()> {
         let client = get_client().await;
+        let fx_name = "test_scylla_stream_statement";
 
-        let id = "test_scylla_get";
+        let models = [
+            Tst::with_id("test_scylla_stream_statement1").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_statement2").with_name(fx_name),
+            Tst::with_id("test_scylla_stream_statement3").with_name(fx_name),
+        ];
 
-        // Create model
-        let model = Tst::with_id(id);
-        client.insert(&model).await?;
+        // Create models
+        client.insert_many(&models, 3).await?;
 
         // Test
-        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
+        let mut statement = Statement::new("SELECT id, name FROM users WHERE id IN ?");
+        statement.set_page_size(1);
 
-        // Clear
+        let ids: Vec<&str> = models.iter().map(|m| m.id.as_str()).collect();
+        let mut got: Vec<Tst> = client
+            .stream_statement(statement, (ids,))
+            .await?
+            .try_collect()
+            .await?;
+        got.sort();
 
-        client.delete(&model).await?;
+        assert_eq!(models.to_vec(), got);
+
+        // Clear
+        client.delete_many(&models, 3).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_scylla_get_optional() -> Result<()> {
+    async fn test_scylla_insert() -> Result<()> {
         let client = get_client().await;
 
-        let id = "test_scylla_maybe_get";
-
-        assert!(client
-            .get_optional(Tst::maybe_find_first_by_id(id.into()))
-            .await?
-            .is_none());
+        let id = "test_scylla_insert";
 
         // Create model
         let model = Tst::with_id(id);
         client.insert(&model).await?;
 
-        assert!(client
-            .get_optional(Tst::maybe_find_first_by_id(id.into()))
-            .await?
-            .is_some());
+        // Test
+        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
 
         // Clear
         client.delete(&model).await?;
@@ -1804,119 +6852,121 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_scylla_get_many() -> Result<()> {
+    async fn test_scylla_insert_returning() -> Result<()> {
         let client = get_client().await;
-        let fx_name = "test_scylla_get_many";
-
-        let ids = vec![
-            "test_scylla_get_many1",
-            "test_scylla_get_many2",
-            "test_scylla_get_many3",
-        ];
-
-        let models = ids
-            .iter()
-            .map(|id| Tst::with_id(id).with_name(fx_name))
-            .collect::<Vec<Tst>>();
 
-        // Create models
-        client.insert_many(&models, 3).await?;
+        let model = Tst::with_id("test_scylla_insert_returning").with_name("John");
 
         // Test
-        let queries = ids
-            .iter()
-            .map(|id| Tst::find_by_id(id.to_string()))
-            .collect::<Vec<_>>();
-        let mut got = client.get_many(queries).await?;
-        got.sort();
-
-        assert_eq!(models[0], got[0]);
-        assert_eq!(models[1], got[1]);
-        assert_eq!(models[2], got[2]);
+        let stored = client.insert_returning(&model).await?;
+        assert_eq!(model, stored);
+        assert_eq!(stored, client.reload(&model).await?);
 
         // Clear
-        client.delete_many(&got, 3).await?;
+        client.delete(&model).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_scylla_stream() -> Result<()> {
+    async fn test_scylla_insert_many() -> Result<()> {
         let client = get_client().await;
-        let fx_name = "test_scylla_stream";
+        let fx_name = "test_scylla_insert_many";
 
         let models = [
-            Tst::with_id("test_scylla_stream1").with_name(fx_name),
-            Tst::with_id("test_scylla_stream2").with_name(fx_name),
-            Tst::with_id("test_scylla_stream3").with_name(fx_name),
-            Tst::with_id("test_scylla_stream4").with_name(fx_name),
-            Tst::with_id("test_scylla_stream5").with_name(fx_name),
-            Tst::with_id("test_scylla_stream6").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many1").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many2").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many3").with_name(fx_name),
         ];
 
         // Create models
-        client.insert_many(&models, 6).await?;
+        client.insert_many(&models, 3).await?;
 
         // Test
-        let mut stream = client
-            .stream(Tst::find_by_name(fx_name.to_string()))
+        let mut find = client
+            .stream(Tst::find_by_name(fx_name.into()))
+            .await?
+            .try_collect()
             .await?;
+        assert_eq!(3, find.len());
 
-        let mut got = vec![];
+        find.sort();
 
-        while let Some(Ok(model)) = stream.next().await {
-            got.push(model);
-        }
+        assert_eq!(models[0], find[0]);
+        assert_eq!(models[1], find[1]);
+        assert_eq!(models[2], find[2]);
 
-        assert_eq!(6, got.len());
+        // Clear
+        client.delete_many(&find, 3).await?;
 
-        got.sort();
+        Ok(())
+    }
 
-        assert_eq!(models[0], got[0]);
-        assert_eq!(models[1], got[1]);
-        assert_eq!(models[2], got[2]);
-        assert_eq!(models[3], got[3]);
-        assert_eq!(models[4], got[4]);
-        assert_eq!(models[5], got[5]);
+    #[tokio::test]
+    async fn test_scylla_many_zero_chunk_size() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_many_zero_chunk_size";
 
-        // Clear
-        client.delete_many(&got, 6).await?;
+        let models = [
+            Tst::with_id("test_scylla_many_zero_chunk_size1").with_name(fx_name),
+            Tst::with_id("test_scylla_many_zero_chunk_size2").with_name(fx_name),
+        ];
+
+        // Test: a chunk size of 0 applies one entity per batch
+        client.insert_many(&models, 0).await?;
+        client.update_many(&models, 0).await?;
+        assert_eq!(2, client.count(Tst::find_by_name(fx_name.into())).await?);
+
+        client.delete_many(&models, 0).await?;
+        assert_eq!(0, client.count(Tst::find_by_name(fx_name.into())).await?);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_scylla_insert() -> Result<()> {
+    async fn test_scylla_insert_many_failed_chunk() -> Result<()> {
         let client = get_client().await;
+        let fx_name = "test_scylla_insert_many_failed_chunk";
 
-        let id = "test_scylla_insert";
-
-        // Create model
-        let model = Tst::with_id(id);
-        client.insert(&model).await?;
+        // The middle chunk exceeds the batch size limit
+        let models = [
+            Tst::with_id("test_scylla_insert_many_failed_chunk1").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many_failed_chunk2")
+                .with_name("x".repeat(2 * 1024 * 1024)),
+            Tst::with_id("test_scylla_insert_many_failed_chunk3").with_name(fx_name),
+        ];
 
         // Test
-        assert_eq!(model, client.get(Tst::find_by_id(id.into())).await?);
+        let err = client.insert_many(&models, 1).await.unwrap_err();
+        assert!(matches!(err, Error::BatchChunk { chunk: 1, .. }));
+
+        // Chunks after the failing one are not applied
+        let find: Vec<Tst> = client
+            .stream(Tst::find_by_name(fx_name.into()))
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(vec![models[0].clone()], find);
 
         // Clear
-        client.delete(&model).await?;
+        client.delete_many(&find, 1).await?;
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_scylla_insert_many() -> Result<()> {
+    async fn test_scylla_insert_many_unlogged() -> Result<()> {
         let client = get_client().await;
-        let fx_name = "test_scylla_insert_many";
+        let fx_name = "test_scylla_insert_many_unlogged";
 
         let models = [
-            Tst::with_id("test_scylla_insert_many1").with_name(fx_name),
-            Tst::with_id("test_scylla_insert_many2").with_name(fx_name),
-            Tst::with_id("test_scylla_insert_many3").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many_unlogged1").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many_unlogged2").with_name(fx_name),
+            Tst::with_id("test_scylla_insert_many_unlogged3").with_name(fx_name),
         ];
 
         // Create models
-        client.insert_many(&models, 3).await?;
+        client.insert_many_unlogged(&models, 3).await?;
 
         // Test
         let mut find = client
@@ -1932,8 +6982,42 @@ mod tests {
         assert_eq!(models[1], find[1]);
         assert_eq!(models[2], find[2]);
 
+        assert!(matches!(
+            client
+                .insert_many_with_type(&models, 3, BatchType::Counter)
+                .await,
+            Err(Error::UnsupportedBatchType(BatchType::Counter))
+        ));
+
         // Clear
-        client.delete_many(&find, 3).await?;
+        client
+            .delete_many_with_type(&find, 3, BatchType::Unlogged)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_insert_many_logs_chunks() -> Result<()> {
+        let (logs, _guard) = crate::test_utils::capture_logs();
+
+        let client = get_client().await;
+        let fx_name = "test_scylla_insert_many_logs_chunks";
+
+        let models = (0..7)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect::<Vec<Tst>>();
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test
+        assert!(logs
+            .contents()
+            .contains("Insert batch (Logged): 7 items, chunk size 3, 3 chunks"));
+
+        // Clear
+        client.delete_many(&models, 3).await?;
 
         Ok(())
     }
@@ -2032,6 +7116,103 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_scylla_first_optional() -> Result<()> {
+        let client = get_client().await;
+        let fx_name = "test_scylla_first_optional";
+
+        let models: Vec<Tst> = (0..10)
+            .map(|i| Tst::with_id(&format!("{fx_name}{i}")).with_name(fx_name))
+            .collect();
+
+        // Test missing rows
+        assert_eq!(
+            None,
+            client
+                .first_optional(Tst::find_by_name(fx_name.to_string()))
+                .await?
+        );
+
+        // Create models
+        client.insert_many(&models, 10).await?;
+
+        // Test: the first row of the stream is returned
+        let first = client
+            .first_optional(Tst::find_by_name(fx_name.to_string()))
+            .await?;
+
+        let expected = client
+            .stream(Tst::find_by_name(fx_name.to_string()))
+            .await?
+            .next()
+            .await
+            .transpose()?;
+        assert!(first.is_some());
+        assert_eq!(expected, first);
+
+        // Clear
+        client.delete_many(&models, 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_insert_many_dedup() -> Result<()> {
+        let client = get_client().await;
+
+        let models = [
+            Tst::with_id("test_scylla_insert_many_dedup1").with_name("first"),
+            Tst::with_id("test_scylla_insert_many_dedup2").with_name("other"),
+            Tst::with_id("test_scylla_insert_many_dedup1").with_name("last"),
+        ];
+        client.delete_many(&models[1..], 10).await?;
+
+        // Test: conflict fails without writing anything
+        let err = client
+            .insert_many_dedup(&models, 10, DedupStrategy::Error)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::DuplicatePrimaryKey { table, index: 2 } if table == "users"));
+        assert!(!client.exists::<Tst>((models[0].id.clone(),)).await?);
+
+        // Test: last occurrence wins
+        client
+            .insert_many_dedup(&models, 10, DedupStrategy::KeepLast)
+            .await?;
+
+        let got = client.get(Tst::find_by_id(models[0].id.clone())).await?;
+        assert_eq!(models[2], got);
+        assert_eq!(models[1], client.reload(&models[1]).await?);
+
+        // Clear
+        client.delete_many(&models[1..], 10).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_delete_by_pk() -> Result<()> {
+        let client = get_client().await;
+
+        let id = "test_scylla_delete_by_pk";
+
+        // Create model
+        let model = Tst::with_id(id).with_name(id);
+        client.insert(&model).await?;
+        assert!(client.exists::<Tst>((id.to_string(),)).await?);
+
+        // Test
+        client.delete_by_pk::<Tst>((id.to_string(),)).await?;
+
+        assert!(!client.exists::<Tst>((id.to_string(),)).await?);
+        assert!(client.get(Tst::find_by_id(id.into())).await.is_err());
+
+        // Deleting a missing row succeeds
+        client.delete_by_pk::<Tst>((id.to_string(),)).await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_scylla_delete_many() -> Result<()> {
         let client = get_client().await;
@@ -2084,6 +7265,88 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_scylla_count_cache() -> Result<()> {
+        let client = get_client().await.with_count_cache(Duration::from_secs(60));
+        let fx_name = "test_scylla_count_cache";
+
+        let models = [
+            Tst::with_id("test_scylla_count_cache1").with_name(fx_name),
+            Tst::with_id("test_scylla_count_cache2").with_name(fx_name),
+        ];
+
+        // Create models
+        client.insert_many(&models, 2).await?;
+
+        // Test
+        let cache = client.count_cache.clone().unwrap();
+
+        assert_eq!(
+            2,
+            client
+                .count_cached(Tst::find_by_name(fx_name.into()), fx_name)
+                .await?
+        );
+        assert_eq!(1, cache.scans());
+
+        // Second count within the TTL doesn't re-scan, also from a clone
+        assert_eq!(
+            2,
+            client
+                .clone()
+                .count_cached(Tst::find_by_name(fx_name.into()), fx_name)
+                .await?
+        );
+        assert_eq!(1, cache.scans());
+
+        // Plain count doesn't use the cache
+        assert_eq!(2, client.count(Tst::find_by_name(fx_name.into())).await?);
+        assert_eq!(1, cache.scans());
+
+        // Clear
+        client.delete_many(&models, 2).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scylla_count_cache_values() -> Result<()> {
+        let client = get_client().await.with_count_cache(Duration::from_secs(60));
+        let fx_name1 = "test_scylla_count_cache_values1";
+        let fx_name2 = "test_scylla_count_cache_values2";
+
+        let models = [
+            Tst::with_id("test_scylla_count_cache_values1").with_name(fx_name1),
+            Tst::with_id("test_scylla_count_cache_values2").with_name(fx_name2),
+            Tst::with_id("test_scylla_count_cache_values3").with_name(fx_name2),
+        ];
+
+        // Create models
+        client.insert_many(&models, 3).await?;
+
+        // Test: same finder with different values gets different counts
+        assert_eq!(
+            1,
+            client
+                .count_cached(Tst::find_by_name(fx_name1.into()), fx_name1)
+                .await?
+        );
+        assert_eq!(
+            2,
+            client
+                .count_cached(Tst::find_by_name(fx_name2.into()), fx_name2)
+                .await?
+        );
+
+        let cache = client.count_cache.clone().unwrap();
+        assert_eq!(2, cache.scans());
+
+        // Clear
+        client.delete_many(&models, 3).await?;
+
+        Ok(())
+    }
 }
 
 // endregion: --- Tests
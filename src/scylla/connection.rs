@@ -12,8 +12,15 @@ use charybdis::scylla::{
         session_builder::SessionBuilder,
     },
     frame::Compression,
+    statement::Consistency,
 };
-use std::time::Duration;
+use futures::future::BoxFuture;
+use std::{fmt, sync::Arc, time::Duration};
+
+/// A callback invoked with the session right after `Client::connect` builds it.
+///
+/// See `ConnectionParams::on_connect`.
+pub type OnConnect = Arc<dyn for<'a> Fn(&'a Session) -> BoxFuture<'a, Result<()>> + Send + Sync>;
 
 /// Default implementation for ConnectionParams
 ///
@@ -28,12 +35,20 @@ impl Default for ConnectionParams {
     /// - `caching_capacity`: 1000 prepared statements
     /// - `connection_timeout`: 3 seconds
     /// - `compression`: None (no compression)
+    /// - `tcp_nodelay`: true (disable Nagle's algorithm)
+    /// - `tcp_keepalive`: None (no TCP keepalive)
     /// - `fetch_keyspaces`: Empty vector (no keyspaces pre-fetched)
     /// - `keyspace_case_sensitive`: true
     /// - `use_keyspace`: None (no default keyspace)
     /// - `migrate`: true (run migrations by default)
     /// - `recreate_keyspace`: false (don't recreate keyspace by default)
+    /// - `create_missing_keyspace`: true (create the keyspace if it doesn't exist)
     /// - `init_files`: Empty vector (no initialization files)
+    /// - `init_files_before_keyspace`: false (run init files after keyspace setup)
+    /// - `set_active_keyspace`: true (`USE` the keyspace after setting it up)
+    /// - `on_connect`: None (no connection-init callback)
+    /// - `schema_consistency`: None (driver default consistency for schema changes)
+    /// - `prepare_statements`: Empty vector (no statements prepared at connect)
     ///
     /// # Returns
     ///
@@ -45,12 +60,20 @@ impl Default for ConnectionParams {
             caching_capacity: 1000,
             connection_timeout: Duration::from_secs(3),
             compression: None,
+            tcp_nodelay: true,
+            tcp_keepalive: None,
             fetch_keyspaces: vec![],
             keyspace_case_sensitive: true,
             use_keyspace: None,
             migrate: true,
             recreate_keyspace: false,
+            create_missing_keyspace: true,
             init_files: vec![],
+            init_files_before_keyspace: false,
+            set_active_keyspace: true,
+            on_connect: None,
+            schema_consistency: None,
+            prepare_statements: vec![],
         }
     }
 }
@@ -81,7 +104,7 @@ impl Default for ConnectionParams {
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ConnectionParams {
     /// The URI of the ScyllaDB node to connect to
     ///
@@ -100,6 +123,9 @@ pub struct ConnectionParams {
     /// The caching session will store up to this many prepared statements
     /// in memory to avoid re-preparing frequently used queries. Higher values
     /// improve performance but use more memory.
+    ///
+    /// Clients created with `Client::connect` can change it later with
+    /// `Client::resize_statement_cache`, which keeps the driver session.
     pub caching_capacity: usize,
 
     /// Optional compression algorithm to use for network communication
@@ -108,6 +134,18 @@ pub struct ConnectionParams {
     /// Common options include LZ4 and Snappy. None means no compression.
     pub compression: Option<Compression>,
 
+    /// Whether to set `TCP_NODELAY` on connections to the cluster
+    ///
+    /// When true (default), Nagle's algorithm is disabled and small requests
+    /// are sent immediately, which lowers latency at the cost of more packets.
+    pub tcp_nodelay: bool,
+
+    /// Optional interval of TCP keepalive probes on connections to the cluster
+    ///
+    /// Keepalive probes detect dead connections and keep idle connections open
+    /// through firewalls and load balancers. None leaves keepalive disabled.
+    pub tcp_keepalive: Option<Duration>,
+
     /// List of keyspaces to fetch metadata for during connection
     ///
     /// Pre-fetching keyspace metadata can improve query performance by avoiding
@@ -140,15 +178,141 @@ pub struct ConnectionParams {
     /// but should never be used in production.
     pub recreate_keyspace: bool,
 
+    /// Whether to create the keyspace if it doesn't exist
+    ///
+    /// When true (default), the keyspace specified in `use_keyspace` is created
+    /// if it is missing. When false, connecting fails with `Error::KeyspaceNotFound`
+    /// instead, which is the safer choice for production environments.
+    /// Has no effect if `recreate_keyspace` is set.
+    pub create_missing_keyspace: bool,
+
     /// List of CQL files to execute during initialization
     ///
     /// These files will be executed in order after the connection is established
     /// and before migrations (if enabled). Useful for setting up initial data,
     /// creating custom types, or running setup scripts.
+    ///
+    /// See `init_files_before_keyspace` for ordering relative to keyspace setup.
     pub init_files: Vec<String>,
+
+    /// Whether to execute `init_files` before keyspace setup
+    ///
+    /// When false (default), the connection phases run in this order:
+    /// keyspace creation, `USE` keyspace, init files, migrations.
+    ///
+    /// When true, init files run first, before the keyspace is created or
+    /// selected: init files, keyspace creation, `USE` keyspace, migrations.
+    /// Use this when the init files create the keyspace themselves; note that
+    /// statements in such files must use fully qualified table names.
+    pub init_files_before_keyspace: bool,
+
+    /// Whether to make `use_keyspace` the active keyspace of the session
+    ///
+    /// When true (default), the keyspace is selected with `USE` after it is
+    /// set up, so unqualified table names resolve to it.
+    ///
+    /// When false, the keyspace is still created (or checked) and migrated, but
+    /// the session is left without an active keyspace. Use this for clients
+    /// working with several keyspaces, where every query qualifies its tables.
+    pub set_active_keyspace: bool,
+
+    /// Optional callback invoked once after the session is built
+    ///
+    /// Use it to set session options or register UDFs right after connecting.
+    /// The callback runs after keyspace setup and init files, and before
+    /// migrations. An error returned by the callback aborts `Client::connect`.
+    ///
+    /// See `with_on_connect` for setting it from a closure.
+    pub on_connect: Option<OnConnect>,
+
+    /// Optional consistency level for schema changes
+    ///
+    /// When set, keyspace and table management statements issued by the client
+    /// (e.g. `create_keyspace`, `drop_table`) use this consistency instead of the
+    /// driver default or the CRUD parameters. Useful in multi-DC setups.
+    pub schema_consistency: Option<Consistency>,
+
+    /// List of CQL statements to prepare during connection
+    ///
+    /// The statements are prepared after migrations and stored in the cache of
+    /// the caching session, so their first execution doesn't pay for preparation.
+    /// A statement that fails to prepare aborts `Client::connect` with
+    /// `Error::PrepareStatement` identifying the query.
+    pub prepare_statements: Vec<String>,
+}
+
+impl fmt::Debug for ConnectionParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionParams")
+            .field("uri", &self.uri)
+            .field("connection_timeout", &self.connection_timeout)
+            .field("caching_capacity", &self.caching_capacity)
+            .field("compression", &self.compression)
+            .field("tcp_nodelay", &self.tcp_nodelay)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("fetch_keyspaces", &self.fetch_keyspaces)
+            .field("use_keyspace", &self.use_keyspace)
+            .field("keyspace_case_sensitive", &self.keyspace_case_sensitive)
+            .field("migrate", &self.migrate)
+            .field("recreate_keyspace", &self.recreate_keyspace)
+            .field("create_missing_keyspace", &self.create_missing_keyspace)
+            .field("init_files", &self.init_files)
+            .field(
+                "init_files_before_keyspace",
+                &self.init_files_before_keyspace,
+            )
+            .field("set_active_keyspace", &self.set_active_keyspace)
+            .field("on_connect", &self.on_connect.as_ref().map(|_| "Fn"))
+            .field("schema_consistency", &self.schema_consistency)
+            .field("prepare_statements", &self.prepare_statements)
+            .finish()
+    }
 }
 
 impl ConnectionParams {
+    /// Sets the callback invoked once after the session is built
+    ///
+    /// Unlike assigning `on_connect` directly, this method lets the compiler infer
+    /// the signature of a closure, so it can borrow the session in the returned future.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_connect` - The callback to invoke with the session
+    ///
+    /// # Returns
+    ///
+    /// The parameters with the callback set (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use grapple_db::scylla::{Client, ConnectionParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let params = ConnectionParams::default().with_on_connect(|session| {
+    ///         Box::pin(async move {
+    ///             session
+    ///                 .query_unpaged("SELECT release_version FROM system.local", &[])
+    ///                 .await?;
+    ///
+    ///             Ok(())
+    ///         })
+    ///     });
+    ///
+    ///     let client = Client::connect(&params).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_on_connect<F>(mut self, on_connect: F) -> Self
+    where
+        F: for<'a> Fn(&'a Session) -> BoxFuture<'a, Result<()>> + Send + Sync + 'static,
+    {
+        self.on_connect = Some(Arc::new(on_connect));
+        self
+    }
+
     /// Creates a regular ScyllaDB session using these connection parameters
     ///
     /// This method establishes a connection to the ScyllaDB cluster and returns
@@ -183,13 +347,23 @@ impl ConnectionParams {
     /// }
     /// ```
     pub async fn build(&self) -> Result<Session> {
-        let builder = SessionBuilder::new()
+        Ok(self.session_builder().build().await?)
+    }
+
+    /// Creates a session builder configured with these connection parameters
+    fn session_builder(&self) -> SessionBuilder {
+        let mut builder = SessionBuilder::new()
             .known_node(&self.uri)
             .connection_timeout(self.connection_timeout)
             .keyspaces_to_fetch(&self.fetch_keyspaces)
-            .compression(self.compression);
+            .compression(self.compression)
+            .tcp_nodelay(self.tcp_nodelay);
 
-        Ok(builder.build().await?)
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive_interval(interval);
+        }
+
+        builder
     }
 
     /// Creates a caching ScyllaDB session using these connection parameters
@@ -258,3 +432,32 @@ impl From<&ConnectionParams> for ConnectionParams {
         value.clone()
     }
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_params_tcp_options() {
+        let params = ConnectionParams {
+            tcp_nodelay: false,
+            tcp_keepalive: Some(Duration::from_secs(30)),
+            ..Default::default()
+        };
+
+        let builder = params.session_builder();
+        assert!(!builder.config.tcp_nodelay);
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            builder.config.tcp_keepalive_interval
+        );
+
+        let builder = ConnectionParams::default().session_builder();
+        assert!(builder.config.tcp_nodelay);
+        assert_eq!(None, builder.config.tcp_keepalive_interval);
+    }
+}
+
+// endregion: --- Tests
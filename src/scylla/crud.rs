@@ -3,9 +3,9 @@
 //! This module provides the `CrudParams` struct, which encapsulates the
 //! configuration options for performing Create, Read, Update, and Delete
 //! (CRUD) operations with Charybdis. It allows users to specify consistency
-//! levels, timeouts, and timestamps for their operations, ensuring that
-//! these settings are consistently applied across different database
-//! interactions.
+//! levels, timeouts, timestamps and a retry policy for their operations,
+//! ensuring that these settings are consistently applied across different
+//! database interactions.
 //!
 //! The module includes methods to apply these parameters to both batch
 //! operations and individual queries, facilitating a streamlined approach
@@ -36,6 +36,8 @@
 //!     consistency: Consistency::Quorum,
 //!     timeout: Some(Duration::from_secs(5)),
 //!     timestamp: Some(1625078400),
+//!     serial_consistency: None,
+//!     retry: None,
 //! };
 //!
 //! // Applying parameters to a batch operation
@@ -50,14 +52,20 @@
 use super::model::Model;
 use super::operations::{CharybdisModelBatch, ModelBatch};
 use super::query::{CharybdisQuery, QueryExecutor};
-use charybdis::scylla::{serialize::row::SerializeRow, statement::Consistency};
+use super::Result;
+use charybdis::scylla::{
+    serialize::row::SerializeRow,
+    statement::{unprepared::Statement, Consistency, SerialConsistency},
+};
+use std::future::Future;
 use std::time::Duration;
+use tracing::warn;
 
 /// Parameters for CRUD operations in Charybdis.
 ///
 /// This struct encapsulates the configuration options for performing CRUD
-/// operations with Charybdis, including consistency levels, timeouts, and
-/// timestamps. It provides methods to apply these parameters to batch
+/// operations with Charybdis, including consistency levels, timeouts,
+/// timestamps and an optional retry policy. It provides methods to apply these parameters to batch
 /// operations and queries, ensuring that the desired settings are used
 /// consistently across different operations.
 ///
@@ -86,6 +94,8 @@ use std::time::Duration;
 ///     consistency: Consistency::Quorum,
 ///     timeout: Some(Duration::from_secs(5)),
 ///     timestamp: Some(1625078400),
+///     serial_consistency: None,
+///     retry: None,
 /// };
 ///
 /// // Applying parameters to a batch operation
@@ -101,6 +111,10 @@ pub struct CrudParams {
     pub consistency: Consistency,
     pub timeout: Option<Duration>,
     pub timestamp: Option<i64>,
+    /// Serial consistency of lightweight transactions. Only applied to raw statements
+    /// (see `apply_statement`), not to Charybdis queries and batches.
+    pub serial_consistency: Option<SerialConsistency>,
+    pub retry: Option<RetryPolicy>,
 }
 
 impl CrudParams {
@@ -108,7 +122,7 @@ impl CrudParams {
     ///
     /// This method configures the provided batch with the consistency level
     /// and timestamp specified in the `CrudParams`. It returns the modified
-    /// batch with the applied settings.
+    /// batch with the applied settings. The serial consistency is not applied.
     ///
     /// # Parameters
     ///
@@ -134,7 +148,9 @@ impl CrudParams {
     ///
     /// This method configures the provided query with the consistency level,
     /// timeout, and timestamp specified in the `CrudParams`. It returns the
-    /// modified query with the applied settings.
+    /// modified query with the applied settings. The serial consistency is not
+    /// applied; use `Client::execute` with a raw statement for lightweight
+    /// transactions requiring it.
     ///
     /// # Parameters
     ///
@@ -157,6 +173,135 @@ impl CrudParams {
             .timeout(self.timeout)
             .timestamp(self.timestamp)
     }
+
+    /// Applies the CRUD parameters to a raw driver statement.
+    ///
+    /// This method configures the provided statement with the consistency
+    /// level, serial consistency, timeout, and timestamp specified in the
+    /// `CrudParams`. Serial consistency is only set if it is specified.
+    ///
+    /// # Parameters
+    ///
+    /// - `statement`: The statement to configure.
+    pub fn apply_statement(&self, statement: &mut Statement) {
+        statement.set_consistency(self.consistency);
+        statement.set_request_timeout(self.timeout);
+        statement.set_timestamp(self.timestamp);
+
+        if let Some(serial_consistency) = self.serial_consistency {
+            statement.set_serial_consistency(Some(serial_consistency));
+        }
+    }
+}
+
+/// Retry policy for operations failing with transient errors.
+///
+/// Coordinator timeouts (`WriteTimeout`, `ReadTimeout`), `Unavailable` and
+/// `Overloaded` errors, as well as client-side request timeouts, are
+/// considered transient. Operations failing with such errors are retried up
+/// to `max_retries` times, waiting `backoff` before the first retry and
+/// doubling the delay before every following one.
+///
+/// Only idempotent operations (e.g. inserts and updates of a model) are
+/// retried by default. Non-idempotent operations, such as raw statements that
+/// may increment counters or append to lists, are retried only if
+/// `retry_non_idempotent` is set.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use grapple_db::scylla::{CrudParams, RetryPolicy};
+///
+/// let params = CrudParams {
+///     retry: Some(RetryPolicy {
+///         max_retries: 3,
+///         backoff: Duration::from_millis(50),
+///         retry_non_idempotent: false,
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+    pub backoff: Duration,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Creates a default `RetryPolicy` retrying idempotent operations
+    /// up to 3 times with a backoff starting at 100 milliseconds.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(100),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Runs an operation, retrying it on transient errors.
+    ///
+    /// # Parameters
+    ///
+    /// - `idempotent`: Whether the operation is safe to repeat. Non-idempotent
+    ///   operations are retried only if `retry_non_idempotent` is set.
+    /// - `operation`: A closure creating the operation future for every attempt.
+    ///
+    /// # Returns
+    ///
+    /// The result of the first successful attempt, or the error of the last
+    /// attempt if all of them failed or the error is not transient.
+    pub async fn run<T, F, Fut>(&self, idempotent: bool, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let retries = if idempotent || self.retry_non_idempotent {
+            self.max_retries
+        } else {
+            0
+        };
+
+        let mut attempt = 0;
+        let mut delay = self.backoff;
+
+        loop {
+            match operation().await {
+                Err(err) if attempt < retries && err.is_transient() => {
+                    attempt += 1;
+
+                    warn!(
+                        "Transient error, retrying ({}/{}) in {:?}: {}",
+                        attempt, retries, delay, err
+                    );
+
+                    tokio::time::sleep(delay).await;
+                    delay = delay.saturating_mul(2);
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+/// Strategy for entities sharing a primary key in a bulk insert.
+///
+/// Rows with the same primary key overwrite each other, so inserting a slice
+/// with duplicates keeps whichever write is applied last. Deduplicating the
+/// input first makes that choice explicit.
+///
+/// # Variants
+///
+/// - `KeepLast` - Keeps the last occurrence of every primary key (default).
+/// - `Error` - Fails with `Error::DuplicatePrimaryKey` without inserting anything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupStrategy {
+    #[default]
+    KeepLast,
+    Error,
 }
 
 /// Converts a reference to `CrudParams` into an owned `CrudParams`.
@@ -169,3 +314,96 @@ impl From<&CrudParams> for CrudParams {
         value.clone()
     }
 }
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use charybdis::scylla::errors::ExecutionError;
+
+    use super::*;
+    use crate::scylla::Error;
+
+    fn transient() -> Error {
+        Error::Execution(ExecutionError::RequestTimeout(Duration::from_millis(1)))
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            backoff: Duration::from_millis(1),
+            retry_non_idempotent: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_max_retries() {
+        let attempts = AtomicUsize::new(0);
+
+        // Idempotent operation is retried up to `max_retries` times
+        let res: Result<()> = policy()
+            .run(true, || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(transient())
+            })
+            .await;
+
+        assert!(res.unwrap_err().is_transient());
+        assert_eq!(3, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_recovers() {
+        let attempts = AtomicUsize::new(0);
+
+        // Operation succeeds after a transient error
+        let res = policy()
+            .run(true, || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(transient()),
+                    n => Ok(n),
+                }
+            })
+            .await;
+
+        assert_eq!(1, res.unwrap());
+        assert_eq!(2, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_non_idempotent() {
+        let attempts = AtomicUsize::new(0);
+
+        // Non-idempotent operation is not retried without opt-in
+        let res: Result<()> = policy()
+            .run(false, || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(transient())
+            })
+            .await;
+
+        assert!(res.is_err());
+        assert_eq!(1, attempts.load(Ordering::SeqCst));
+
+        // Opt-in enables retries
+        let attempts = AtomicUsize::new(0);
+        let policy = RetryPolicy {
+            retry_non_idempotent: true,
+            ..policy()
+        };
+
+        let res: Result<()> = policy
+            .run(false, || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(transient())
+            })
+            .await;
+
+        assert!(res.is_err());
+        assert_eq!(3, attempts.load(Ordering::SeqCst));
+    }
+}
+
+// endregion: --- Tests
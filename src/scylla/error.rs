@@ -1,3 +1,4 @@
+use charybdis::scylla::errors::{DbError, ExecutionError, RequestAttemptError};
 use derive_more::derive::From;
 use serde::Serialize;
 
@@ -18,8 +19,33 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// - `IntoRows` - Represents an error that occurs when converting results into rows.
 /// - `Rows` - Represents an error related to row operations.
 /// - `Deserialization` - Represents an error that occurs during deserialization of data.
+/// - `TypeCheck` - Represents a mismatch between the columns returned by a query and the
+///   type the rows are deserialized into.
+/// - `NextRow` - Represents an error that occurs while fetching or deserializing the next
+///   row of a typed row stream.
 /// - `UseKeyspace` - Represents an error that occurs when using a specific keyspace.
 /// - `Charybdis` - Represents an error from the Charybdis library.
+/// - `Io` - Represents an I/O error, e.g. when reading a CQL file.
+/// - `ExecuteFile` - Represents an error that occurs while executing a CQL file,
+///   carrying the name of the failing file and the underlying error.
+/// - `PrepareStatement` - Represents an error that occurs while preparing a statement listed in
+///   `ConnectionParams::prepare_statements`, carrying the failing query and the underlying error.
+/// - `SchemaAgreement` - Represents an error that occurs while checking schema agreement.
+/// - `KeyspaceNotFound` - Represents a missing keyspace that is not allowed to be created
+///   automatically. Contains the name of the keyspace.
+/// - `UnsupportedBatchType` - Represents a batch type that cannot be used for the requested
+///   batch operation (e.g. a counter batch for inserts).
+/// - `NotFound` - Represents a row that was expected to exist but was not found. Contains
+///   the name of the table.
+/// - `DuplicatePrimaryKey` - Represents an entity sharing its primary key with an earlier
+///   entity of a bulk insert, carrying the name of the table and the index of the entity.
+/// - `MissingKeyspace` - Represents a query that failed because no keyspace is set on the
+///   session, carrying the keyspace the client expected and the underlying error.
+/// - `BatchChunk` - Represents a failed chunk of a chunked batch operation, carrying the index
+///   of the failing chunk and the underlying error. Chunks are applied in order, so the index
+///   is also the number of chunks applied before the failure.
+/// - `StatementCacheNotResizable` - Represents an attempt to resize the statement cache of a
+///   client created from an existing cached session, which doesn't share its driver session.
 #[derive(Debug, From)]
 pub enum Error {
     // TBC
@@ -36,9 +62,41 @@ pub enum Error {
     #[from]
     Deserialization(charybdis::scylla::errors::DeserializationError),
     #[from]
+    TypeCheck(charybdis::scylla::errors::TypeCheckError),
+    #[from]
+    NextRow(charybdis::scylla::errors::NextRowError),
+    #[from]
     UseKeyspace(charybdis::scylla::errors::UseKeyspaceError),
     #[from]
     Charybdis(charybdis::errors::CharybdisError),
+    #[from]
+    SchemaAgreement(charybdis::scylla::errors::SchemaAgreementError),
+    #[from]
+    Io(std::io::Error),
+    ExecuteFile {
+        filename: String,
+        error: Box<Error>,
+    },
+    PrepareStatement {
+        query: String,
+        error: Box<Error>,
+    },
+    UnsupportedBatchType(charybdis::scylla::statement::batch::BatchType),
+    KeyspaceNotFound(String),
+    NotFound(String),
+    DuplicatePrimaryKey {
+        table: String,
+        index: usize,
+    },
+    MissingKeyspace {
+        expected: Option<String>,
+        error: Box<Error>,
+    },
+    BatchChunk {
+        chunk: usize,
+        error: Box<Error>,
+    },
+    StatementCacheNotResizable,
 }
 
 impl Serialize for Error {
@@ -72,6 +130,14 @@ impl Serialize for Error {
                 // Serialize the Deserialization error as a string
                 serializer.serialize_str(&deserialization_error.to_string())
             }
+            Error::TypeCheck(type_check_error) => {
+                // Serialize the TypeCheck error as a string
+                serializer.serialize_str(&type_check_error.to_string())
+            }
+            Error::NextRow(next_row_error) => {
+                // Serialize the NextRow error as a string
+                serializer.serialize_str(&next_row_error.to_string())
+            }
             Error::UseKeyspace(use_keyspace_error) => {
                 // Serialize the UseKeyspace error as a string
                 serializer.serialize_str(&use_keyspace_error.to_string())
@@ -80,6 +146,132 @@ impl Serialize for Error {
                 // Serialize the Charybdis error as a string
                 serializer.serialize_str(&charybdis_error.to_string())
             }
+            Error::SchemaAgreement(schema_agreement_error) => {
+                // Serialize the SchemaAgreement error as a string
+                serializer.serialize_str(&schema_agreement_error.to_string())
+            }
+            Error::Io(io_error) => {
+                // Serialize the Io error as a string
+                serializer.serialize_str(&io_error.to_string())
+            }
+            Error::ExecuteFile { filename, error } => {
+                // Serialize the ExecuteFile error as a string with the failing file name
+                serializer.serialize_str(&format!("{filename}: {error}"))
+            }
+            Error::PrepareStatement { query, error } => {
+                // Serialize the PrepareStatement error as a string with the failing query
+                serializer.serialize_str(&format!("Failed to prepare '{query}': {error}"))
+            }
+            Error::UnsupportedBatchType(batch_type) => {
+                // Serialize the UnsupportedBatchType error as a string
+                serializer.serialize_str(&format!("Unsupported batch type: {batch_type:?}"))
+            }
+            Error::KeyspaceNotFound(keyspace) => {
+                // Serialize the KeyspaceNotFound error as a string
+                serializer.serialize_str(&format!("Keyspace not found: {keyspace}"))
+            }
+            Error::NotFound(table) => {
+                // Serialize the NotFound error as a string
+                serializer.serialize_str(&format!("Row not found in table: {table}"))
+            }
+            Error::DuplicatePrimaryKey { table, index } => {
+                // Serialize the DuplicatePrimaryKey error as a string
+                serializer.serialize_str(&format!(
+                    "Duplicate primary key in table {table} at index {index}"
+                ))
+            }
+            Error::MissingKeyspace { .. } => {
+                // Serialize the MissingKeyspace error as a string with the expected keyspace
+                serializer.serialize_str(&self.to_string())
+            }
+            Error::BatchChunk { chunk, error } => {
+                // Serialize the BatchChunk error as a string with the failing chunk
+                serializer.serialize_str(&format!(
+                    "Batch chunk {chunk} failed after {chunk} successful chunks: {error}"
+                ))
+            }
+            Error::StatementCacheNotResizable => {
+                // Serialize the StatementCacheNotResizable error as a string
+                serializer.serialize_str(
+                    "Statement cache of a client created from a cached session can't be resized",
+                )
+            }
+        }
+    }
+}
+
+impl Error {
+    /// Checks whether the error is transient and the request may succeed if repeated.
+    ///
+    /// Coordinator timeouts, `Unavailable` and `Overloaded` errors, as well as
+    /// client-side request timeouts, are considered transient.
+    pub fn is_transient(&self) -> bool {
+        match self.execution_error() {
+            Some(ExecutionError::RequestTimeout(_)) => true,
+            Some(_) => matches!(
+                self.db_error(),
+                Some(
+                    DbError::WriteTimeout { .. }
+                        | DbError::ReadTimeout { .. }
+                        | DbError::Unavailable { .. }
+                        | DbError::Overloaded
+                )
+            ),
+            None => false,
+        }
+    }
+
+    /// Checks whether the coordinator rejected the request because not enough
+    /// replicas were alive to satisfy the requested consistency level.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self.db_error(), Some(DbError::Unavailable { .. }))
+    }
+
+    /// Checks whether the coordinator timed out waiting for replicas to respond
+    /// to a read request.
+    pub fn is_read_timeout(&self) -> bool {
+        matches!(self.db_error(), Some(DbError::ReadTimeout { .. }))
+    }
+
+    /// Checks whether the coordinator timed out waiting for replicas to
+    /// acknowledge a write request.
+    pub fn is_write_timeout(&self) -> bool {
+        matches!(self.db_error(), Some(DbError::WriteTimeout { .. }))
+    }
+
+    /// Returns the wrapped execution error, if any.
+    ///
+    /// Charybdis wraps driver errors, so its source chain is searched as well.
+    /// Errors of a failed batch chunk are unwrapped.
+    fn execution_error(&self) -> Option<&ExecutionError> {
+        match self {
+            Error::Execution(execution_error) => Some(execution_error),
+            Error::BatchChunk { error, .. } => error.execution_error(),
+            Error::Charybdis(charybdis_error) => {
+                let mut source: Option<&(dyn std::error::Error + 'static)> =
+                    Some(charybdis_error as &(dyn std::error::Error + 'static));
+
+                while let Some(error) = source {
+                    if let Some(execution_error) = error.downcast_ref::<ExecutionError>() {
+                        return Some(execution_error);
+                    }
+
+                    source = error.source();
+                }
+
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the database error returned by the last attempt, if any.
+    fn db_error(&self) -> Option<&DbError> {
+        match self.execution_error()? {
+            ExecutionError::LastAttemptError(RequestAttemptError::DbError(db_error, _)) => {
+                Some(db_error)
+            }
+            _ => None,
         }
     }
 }
@@ -88,10 +280,133 @@ impl Serialize for Error {
 
 impl core::fmt::Display for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Error::MissingKeyspace { expected, error } => {
+                let hint = match expected {
+                    Some(keyspace) => format!("expected keyspace '{keyspace}'"),
+                    None => "no keyspace configured on the client".to_string(),
+                };
+
+                write!(
+                    fmt,
+                    "No keyspace is set on the session ({hint}), call `use_keyspace` or qualify the table name: {error}"
+                )
+            }
+            _ => write!(fmt, "{self:?}"),
+        }
     }
 }
 
 impl std::error::Error for Error {}
 
 // endregion: --- Error Boilerplate
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use charybdis::scylla::errors::WriteType;
+    use charybdis::scylla::statement::Consistency;
+
+    use super::*;
+
+    fn db_error(db_error: DbError) -> Error {
+        Error::Execution(ExecutionError::LastAttemptError(
+            RequestAttemptError::DbError(db_error, "test".to_string()),
+        ))
+    }
+
+    fn unavailable() -> Error {
+        db_error(DbError::Unavailable {
+            consistency: Consistency::Quorum,
+            required: 2,
+            alive: 1,
+        })
+    }
+
+    fn read_timeout() -> Error {
+        db_error(DbError::ReadTimeout {
+            consistency: Consistency::Quorum,
+            received: 1,
+            required: 2,
+            data_present: false,
+        })
+    }
+
+    fn write_timeout() -> Error {
+        db_error(DbError::WriteTimeout {
+            consistency: Consistency::Quorum,
+            received: 1,
+            required: 2,
+            write_type: WriteType::Simple,
+        })
+    }
+
+    #[test]
+    fn test_error_is_unavailable() {
+        assert!(unavailable().is_unavailable());
+        assert!(!read_timeout().is_unavailable());
+        assert!(!write_timeout().is_unavailable());
+    }
+
+    #[test]
+    fn test_error_is_read_timeout() {
+        assert!(read_timeout().is_read_timeout());
+        assert!(!unavailable().is_read_timeout());
+        assert!(!write_timeout().is_read_timeout());
+    }
+
+    #[test]
+    fn test_error_is_write_timeout() {
+        assert!(write_timeout().is_write_timeout());
+        assert!(!unavailable().is_write_timeout());
+        assert!(!read_timeout().is_write_timeout());
+    }
+
+    #[test]
+    fn test_error_classification_other() {
+        let request_timeout =
+            Error::Execution(ExecutionError::RequestTimeout(Duration::from_millis(1)));
+        assert!(request_timeout.is_transient());
+        assert!(!request_timeout.is_unavailable());
+        assert!(!request_timeout.is_read_timeout());
+        assert!(!request_timeout.is_write_timeout());
+
+        let overloaded = db_error(DbError::Overloaded);
+        assert!(overloaded.is_transient());
+        assert!(!overloaded.is_unavailable());
+
+        let keyspace = Error::KeyspaceNotFound("test".to_string());
+        assert!(!keyspace.is_transient());
+        assert!(!keyspace.is_unavailable());
+
+        assert!(unavailable().is_transient());
+        assert!(read_timeout().is_transient());
+        assert!(write_timeout().is_transient());
+    }
+
+    #[test]
+    fn test_error_missing_keyspace_message() {
+        let err = Error::MissingKeyspace {
+            expected: Some("test".to_string()),
+            error: Box::new(Error::KeyspaceNotFound("test".to_string())),
+        };
+        assert_eq!(
+            "No keyspace is set on the session (expected keyspace 'test'), call `use_keyspace` or qualify the table name: KeyspaceNotFound(\"test\")",
+            err.to_string()
+        );
+
+        let err = Error::MissingKeyspace {
+            expected: None,
+            error: Box::new(Error::KeyspaceNotFound("test".to_string())),
+        };
+        assert_eq!(
+            "No keyspace is set on the session (no keyspace configured on the client), call `use_keyspace` or qualify the table name: KeyspaceNotFound(\"test\")",
+            err.to_string()
+        );
+    }
+}
+
+// endregion: --- Tests
@@ -7,6 +7,7 @@
 //!
 //! # Modules
 //!
+//! - `cache`: Provides the `CountCache` used to memoize results of count queries.
 //! - `client`: Contains the implementation of the Scylla client for interacting
 //!   with the database.
 //! - `connection`: Defines parameters and methods for establishing and managing
@@ -24,6 +25,7 @@
 
 // region:    --- Modules
 
+mod cache;
 pub mod client;
 mod connection;
 mod crud;
@@ -55,10 +57,13 @@ pub mod macros {
 }
 
 pub use charybdis::macros::scylla::*;
-pub use client::{CachingSession, Client, Compression, Session, SessionConfig, TlsContext};
-pub use connection::ConnectionParams;
-pub use crud::CrudParams;
+pub use client::{
+    CachingSession, Client, Compression, NodeInfo, Session, SessionConfig, TlsContext,
+};
+pub use connection::{ConnectionParams, OnConnect};
+pub use crud::{CrudParams, DedupStrategy, RetryPolicy};
 pub use error::{Error, Result};
 pub use scylla::*;
+pub use tokio_util::sync::CancellationToken;
 
 // endregion: --- Modules
@@ -51,8 +51,12 @@
 //! Ok(())
 //! # }
 //! ```
+//!
+//! The module also provides `collect_capped`, which collects a stream into a
+//! vector with a safety cap on the number of items.
 
 use super::model::Model;
+use super::Result;
 use async_trait::async_trait;
 use futures::StreamExt;
 
@@ -73,6 +77,8 @@ use crate::Pagable;
 /// - `stream`: The underlying Charybdis model stream from which items are fetched.
 /// - `per_page`: The number of items to retrieve per page.
 /// - `page_items`: A vector that holds the items of the current page.
+/// - `exhausted`: Whether the underlying stream is known to be drained.
+/// - `total_items`: The total number of items, if known (see `with_count`).
 ///
 /// # Examples
 ///
@@ -123,6 +129,8 @@ where
     stream: CharybdisModelStream<E>,
     per_page: usize,
     page_items: Vec<E>,
+    exhausted: bool,
+    total_items: Option<usize>,
 }
 
 impl<E> PagableCharybdisStream<E>
@@ -147,8 +155,81 @@ where
             stream,
             per_page,
             page_items: Vec::with_capacity(per_page as usize),
+            exhausted: false,
+            total_items: None,
         }
     }
+
+    /// Creates a new instance of `PagableCharybdisStream` with a known total number of items.
+    ///
+    /// The stream itself can't tell how many items it holds, so the total is usually
+    /// obtained with a separate count query. `Client::paged` runs both together.
+    ///
+    /// # Parameters
+    ///
+    /// - `stream`: The Charybdis model stream to paginate.
+    /// - `per_page`: The number of items to retrieve per page.
+    /// - `count`: The total number of items in the stream.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `PagableCharybdisStream` reporting `total_items` and `total_pages`.
+    pub fn with_count(stream: CharybdisModelStream<E>, per_page: usize, count: usize) -> Self {
+        let mut pagable = Self::new(stream, per_page);
+        pagable.total_items = Some(count);
+        pagable
+    }
+
+    /// Returns the total number of items, if it was provided with `with_count`.
+    #[inline]
+    pub fn total_items(&self) -> Option<usize> {
+        self.total_items
+    }
+
+    /// Returns the total number of pages for the current page size, if the total number
+    /// of items was provided with `with_count`.
+    ///
+    /// The last page may be partial, so the result is rounded up. A page size of 0 has
+    /// no pages.
+    pub fn total_pages(&self) -> Option<usize> {
+        let total_items = self.total_items?;
+
+        if self.per_page == 0 {
+            return Some(0);
+        }
+
+        Some(total_items.div_ceil(self.per_page))
+    }
+
+    /// Checks whether the underlying stream is drained.
+    ///
+    /// The flag becomes `true` once a page returned fewer than `per_page` items.
+    /// A page that exactly fills `per_page` is not treated as the last one, so
+    /// the flag flips only after the next fetch confirms there are no more items.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no more pages are available, `false` otherwise.
+    #[inline]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Changes the number of items retrieved per page.
+    ///
+    /// The new size affects subsequent `next_page` and `skip_page` calls, which allows
+    /// loading bigger chunks as the user scrolls. The items of the current page are kept.
+    /// A size of 0 makes subsequent pages empty: `next_page` returns `None` without
+    /// consuming the stream or marking it as exhausted.
+    ///
+    /// # Parameters
+    ///
+    /// - `per_page`: The new number of items to retrieve per page.
+    pub fn set_per_page(&mut self, per_page: usize) {
+        self.per_page = per_page;
+        self.page_items
+            .reserve(per_page.saturating_sub(self.page_items.len()));
+    }
 }
 
 #[async_trait]
@@ -170,6 +251,10 @@ where
             }
         }
 
+        if available < self.per_page {
+            self.exhausted = true;
+        }
+
         if available == 0 {
             None
         } else {
@@ -182,6 +267,7 @@ where
 
         for _ in 0..self.per_page {
             if self.stream.next().await.is_none() {
+                self.exhausted = true;
                 break;
             }
         }
@@ -192,3 +278,71 @@ where
         &self.page_items
     }
 }
+
+/// Collects a Charybdis model stream into a vector with a cap on the number of items.
+///
+/// Unlike `try_collect`, this function stops after `max` items, so a huge stream
+/// can't exhaust memory. One more item is polled to find out whether the stream
+/// had more items than collected.
+///
+/// # Parameters
+///
+/// - `stream`: The Charybdis model stream to collect.
+/// - `max`: The maximum number of items to collect.
+///
+/// # Returns
+///
+/// A `Result` containing the collected items and a flag that is `true` if more
+/// items remained in the stream.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use grapple_db::scylla::stream::collect_capped;
+/// use grapple_db::scylla::Client;
+/// use grapple_db::scylla::operations::Find;
+///
+/// // Assuming you have a `User` model defined with `Charybdis`
+/// # #[grapple_db::scylla::macros::charybdis_model(
+/// #       table_name = users,
+/// #       partition_keys = [id],
+/// #       clustering_keys = [],
+/// #   )]
+/// # #[derive(Debug, Default)]
+/// # struct User {
+/// #     id: String,
+/// # }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::default().await?;
+///
+/// let stream = client.stream(User::find_all()).await?;
+/// let (users, truncated) = collect_capped(stream, 1000).await?;
+///
+/// if truncated {
+///     println!("Export limited to {} users", users.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_capped<E>(
+    mut stream: CharybdisModelStream<E>,
+    max: usize,
+) -> Result<(Vec<E>, bool)>
+where
+    E: Model + 'static,
+{
+    let mut items = Vec::new();
+
+    while items.len() < max {
+        match stream.next().await {
+            Some(item) => items.push(item?),
+            None => return Ok((items, false)),
+        }
+    }
+
+    let more = stream.next().await.is_some();
+
+    Ok((items, more))
+}
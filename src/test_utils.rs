@@ -0,0 +1,44 @@
+//! Helpers shared by the unit tests of the database clients.
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use tracing::subscriber::DefaultGuard;
+
+/// In-memory writer collecting formatted log output.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LogCapture(Arc<Mutex<Vec<u8>>>);
+
+impl LogCapture {
+    /// Returns everything logged so far.
+    pub(crate) fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+    }
+}
+
+impl io::Write for LogCapture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Installs a thread-local subscriber capturing all events up to `DEBUG` level.
+///
+/// The subscriber stays active until the returned guard is dropped.
+pub(crate) fn capture_logs() -> (LogCapture, DefaultGuard) {
+    let capture = LogCapture::default();
+    let writer = capture.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .with_max_level(tracing::Level::DEBUG)
+        .finish();
+
+    (capture, tracing::subscriber::set_default(subscriber))
+}